@@ -1,20 +1,15 @@
-use rust_exercises::list::List;
 use rust_exercises::list::Methods;
+use rust_exercises::list::{Fifo, Lifo, Ordered, Priority};
 
 fn main() {
     let values = vec![1, 5, 2, 3];
 
-    let lifo = List::lifo();
-    let fifo = List::fifo();
-    let ordered = List::ordered();
-
-    let lists: Vec<Box<dyn Methods<_>>> = vec![lifo, fifo, ordered];
-
-    for val in values {
-        for list in &lists {
-            list.push(val);
-        }
-    }
+    let lists: Vec<Box<dyn Methods<_>>> = vec![
+        Box::new(Lifo::from(values.clone())),
+        Box::new(Fifo::from(values.clone())),
+        Box::new(Ordered::from(values.clone())),
+        Box::new(values.into_iter().collect::<Priority<_>>()),
+    ];
 
     for list in &lists {
         println!("{}", list);
@@ -0,0 +1,157 @@
+use std::io::{Read, Write};
+
+use clap::{Args, Parser, Subcommand};
+
+use rust_exercises::grpcd::queue::queue_client::QueueClient;
+use rust_exercises::grpcd::queue::{Node, PopRequest, PushRequest, SubscribeRequest};
+use rust_exercises::grpcd::Payload;
+
+/// Command-line client for the `queue.Queue` gRPC service.
+#[derive(Parser)]
+#[command(name = "queuectl")]
+struct Cli {
+    /// Address of the queue service.
+    #[arg(long, env = "QUEUE_ENDPOINT", default_value = "http://127.0.0.1:50051")]
+    endpoint: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new list and print its id.
+    Create,
+    /// Push a value onto a list.
+    Push {
+        list_id: String,
+        #[command(flatten)]
+        value: Value,
+        /// Type url of the --hex or --base64 message.
+        #[arg(long)]
+        type_url: Option<String>,
+    },
+    /// Pop the next value from a list.
+    Pop {
+        list_id: String,
+        /// Write bytes payloads as they are instead of hex.
+        #[arg(long)]
+        raw: bool,
+    },
+    /// Print values from a list as they are pushed, until interrupted.
+    Subscribe {
+        list_id: String,
+        /// Write bytes payloads as they are instead of hex.
+        #[arg(long)]
+        raw: bool,
+    },
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Value {
+    /// UTF-8 string, sent as google.protobuf.StringValue.
+    #[arg(long)]
+    string: Option<String>,
+
+    /// File to send as google.protobuf.BytesValue, `-` reads stdin.
+    #[arg(long, value_name = "FILE")]
+    bytes: Option<String>,
+
+    /// Hex encoded message, requires --type-url.
+    #[arg(long, requires = "type_url")]
+    hex: Option<String>,
+
+    /// Base64 encoded message, requires --type-url.
+    #[arg(long, requires = "type_url")]
+    base64: Option<String>,
+}
+
+impl Value {
+    fn into_payload(self, type_url: &str) -> Result<Payload, Box<dyn std::error::Error>> {
+        if let Some(s) = self.string {
+            return Ok(Payload::Text(s));
+        };
+        if let Some(hex) = self.hex {
+            return Ok(Payload::from_hex(type_url, &hex)?);
+        };
+        if let Some(b64) = self.base64 {
+            return Ok(Payload::from_base64(type_url, &b64)?);
+        };
+
+        let mut bytes = vec![];
+        match self.bytes.as_deref() {
+            Some("-") | None => std::io::stdin().read_to_end(&mut bytes)?,
+            Some(path) => std::fs::File::open(path)?.read_to_end(&mut bytes)?,
+        };
+        Ok(Payload::Bytes(bytes))
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+
+    if let Err(e) = run(cli).await {
+        match e.downcast_ref::<tonic::Status>() {
+            Some(status) => eprintln!("queuectl: {:?}: {}", status.code(), status.message()),
+            None => eprintln!("queuectl: {}", e),
+        };
+        std::process::exit(1);
+    };
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    let mut client = QueueClient::connect(cli.endpoint).await?;
+
+    match cli.command {
+        Command::Create => {
+            let list = client.create(()).await?.into_inner();
+            println!("{}", list.id);
+        }
+        Command::Push {
+            list_id,
+            value,
+            type_url,
+        } => {
+            let payload = value.into_payload(type_url.as_deref().unwrap_or_default())?;
+            let node = Node {
+                value: Some(payload.into_any()),
+            };
+            client
+                .push(PushRequest {
+                    list_id,
+                    node: Some(node),
+                })
+                .await?;
+        }
+        Command::Pop { list_id, raw } => {
+            let node = client.pop(PopRequest { list_id }).await?.into_inner();
+            print_node(node, raw)?;
+        }
+        Command::Subscribe { list_id, raw } => {
+            let mut nodes = client
+                .subscribe(SubscribeRequest { list_id })
+                .await?
+                .into_inner();
+            while let Some(node) = nodes.message().await? {
+                print_node(node, raw)?;
+            }
+        }
+    };
+
+    Ok(())
+}
+
+fn print_node(node: Node, raw: bool) -> std::io::Result<()> {
+    match node.value.map(Payload::from_any) {
+        Some(Payload::Bytes(b)) if raw => {
+            let mut stdout = std::io::stdout();
+            stdout.write_all(&b)?;
+            stdout.flush()?;
+        }
+        Some(payload) => println!("{}", payload),
+        None => println!(),
+    };
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use clap::Parser;
+use tonic::transport::Server;
+
+use rust_exercises::grpcd::queue::queue_server::QueueServer;
+use rust_exercises::grpcd::wal::Fsync;
+use rust_exercises::grpcd::QueueService;
+
+/// Serves the `queue.Queue` gRPC service.
+#[derive(Parser)]
+#[command(name = "queued")]
+struct Args {
+    /// Address to listen on.
+    #[arg(long, env = "QUEUED_LISTEN", default_value = "127.0.0.1:50051")]
+    listen: SocketAddr,
+
+    /// Keep lists in a write-ahead log at this path so they survive
+    /// restarts. Without it everything is lost on shutdown.
+    #[arg(long, env = "QUEUED_WAL", value_name = "PATH")]
+    wal: Option<PathBuf>,
+
+    /// When to fsync the log: `always`, `never` or after every N records.
+    #[arg(long, env = "QUEUED_FSYNC", default_value_t = Fsync::Always)]
+    fsync: Fsync,
+}
+
+// Resolves on the first Ctrl-C or, on unix, SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for ctrl-c");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    println!("queued: shutting down");
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let service = match &args.wal {
+        Some(path) => {
+            println!(
+                "queued: using log {} (fsync {})",
+                path.display(),
+                args.fsync
+            );
+            QueueService::open(path, args.fsync)?
+        }
+        None => QueueService::new(),
+    };
+
+    println!("queued: listening on {}", args.listen);
+
+    // subscriptions would otherwise keep their connections, and so
+    // the server, open forever.
+    let service = Arc::new(service);
+    let shutdown = {
+        let service = service.clone();
+        async move {
+            shutdown_signal().await;
+            service.shutdown();
+        }
+    };
+
+    Server::builder()
+        .add_service(QueueServer::from_arc(service))
+        .serve_with_shutdown(args.listen, shutdown)
+        .await?;
+
+    println!("queued: stopped");
+    Ok(())
+}
@@ -1 +1,16 @@
-pub(crate) mod queue;
+pub mod payload;
+pub mod queue;
+pub mod service;
+pub mod wal;
+
+pub use payload::Payload;
+pub use service::QueueService;
+
+#[cfg(test)]
+pub mod payload_test;
+
+#[cfg(test)]
+pub mod service_test;
+
+#[cfg(test)]
+pub mod wal_test;
@@ -0,0 +1,122 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use prost::Message;
+use prost_types::Any;
+
+pub const STRING_VALUE_URL: &str = "type.googleapis.com/google.protobuf.StringValue";
+pub const BYTES_VALUE_URL: &str = "type.googleapis.com/google.protobuf.BytesValue";
+
+/// Value carried by a queue `Node`.
+///
+/// Strings and raw bytes are packed as the well known
+/// `StringValue`/`BytesValue` wrappers, anything else is kept
+/// as an opaque type url plus its encoded message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Payload {
+    Bytes(Vec<u8>),
+    Text(String),
+    Raw { type_url: String, value: Vec<u8> },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PayloadError {
+    Hex(String),
+    Base64(String),
+}
+
+impl Display for PayloadError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            PayloadError::Hex(msg) => write!(fmt, "invalid hex payload: {}", msg),
+            PayloadError::Base64(msg) => write!(fmt, "invalid base64 payload: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for PayloadError {}
+
+impl Payload {
+    pub fn from_hex(type_url: &str, hex: &str) -> Result<Self, PayloadError> {
+        Ok(Payload::Raw {
+            type_url: type_url.to_string(),
+            value: decode_hex(hex)?,
+        })
+    }
+
+    pub fn from_base64(type_url: &str, b64: &str) -> Result<Self, PayloadError> {
+        Ok(Payload::Raw {
+            type_url: type_url.to_string(),
+            value: base64::decode(b64.trim()).map_err(|e| PayloadError::Base64(e.to_string()))?,
+        })
+    }
+
+    pub fn into_any(self) -> Any {
+        match self {
+            Payload::Bytes(b) => Any {
+                type_url: BYTES_VALUE_URL.to_string(),
+                value: b.encode_to_vec(),
+            },
+            Payload::Text(s) => Any {
+                type_url: STRING_VALUE_URL.to_string(),
+                value: s.encode_to_vec(),
+            },
+            Payload::Raw { type_url, value } => Any { type_url, value },
+        }
+    }
+
+    // Unpacks the wrappers we know about, anything that fails
+    // to decode is handed back untouched.
+    pub fn from_any(any: Any) -> Self {
+        match any.type_url.as_str() {
+            BYTES_VALUE_URL => match Vec::<u8>::decode(any.value.as_slice()) {
+                Ok(b) => Payload::Bytes(b),
+                Err(_) => Payload::raw(any),
+            },
+            STRING_VALUE_URL => match String::decode(any.value.as_slice()) {
+                Ok(s) => Payload::Text(s),
+                Err(_) => Payload::raw(any),
+            },
+            _ => Payload::raw(any),
+        }
+    }
+
+    fn raw(any: Any) -> Self {
+        Payload::Raw {
+            type_url: any.type_url,
+            value: any.value,
+        }
+    }
+}
+
+impl Display for Payload {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Payload::Bytes(b) => fmt.write_str(&encode_hex(b)),
+            Payload::Text(s) => fmt.write_str(s),
+            Payload::Raw { type_url, value } => {
+                write!(fmt, "{} {}", type_url, encode_hex(value))
+            }
+        }
+    }
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn decode_hex(hex: &str) -> Result<Vec<u8>, PayloadError> {
+    let hex = hex.trim();
+    if !hex.len().is_multiple_of(2) {
+        return Err(PayloadError::Hex("odd number of digits".to_string()));
+    };
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            hex.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| PayloadError::Hex(format!("bad digits at offset {}", i)))
+        })
+        .collect()
+}
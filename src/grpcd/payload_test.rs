@@ -0,0 +1,55 @@
+use super::payload::{decode_hex, encode_hex, Payload, PayloadError};
+use super::payload::{BYTES_VALUE_URL, STRING_VALUE_URL};
+
+#[test]
+fn test_payload_text_round_trip() {
+    let any = Payload::Text("hello".to_string()).into_any();
+    assert_eq!(any.type_url, STRING_VALUE_URL);
+
+    assert_eq!(Payload::from_any(any), Payload::Text("hello".to_string()));
+}
+
+#[test]
+fn test_payload_bytes_round_trip() {
+    let any = Payload::Bytes(vec![0, 1, 255]).into_any();
+    assert_eq!(any.type_url, BYTES_VALUE_URL);
+
+    assert_eq!(Payload::from_any(any), Payload::Bytes(vec![0, 1, 255]));
+}
+
+#[test]
+fn test_payload_raw_from_hex_and_base64() {
+    let from_hex = Payload::from_hex("type.test/Foo", "0896x1").unwrap_err();
+    assert!(matches!(from_hex, PayloadError::Hex(_)));
+
+    let from_hex = Payload::from_hex("type.test/Foo", "089601").unwrap();
+    let from_b64 = Payload::from_base64("type.test/Foo", "CJYB").unwrap();
+    assert_eq!(from_hex, from_b64);
+
+    let any = from_hex.into_any();
+    assert_eq!(any.type_url, "type.test/Foo");
+    assert_eq!(any.value, vec![0x08, 0x96, 0x01]);
+    assert_eq!(
+        Payload::from_any(any).to_string(),
+        "type.test/Foo 089601".to_string()
+    );
+}
+
+#[test]
+fn test_payload_bad_base64() {
+    let err = Payload::from_base64("type.test/Foo", "!!").unwrap_err();
+    assert!(matches!(err, PayloadError::Base64(_)));
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(encode_hex(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+    assert_eq!(
+        decode_hex("DEADbeef").unwrap(),
+        vec![0xde, 0xad, 0xbe, 0xef]
+    );
+    assert_eq!(decode_hex("").unwrap(), Vec::<u8>::new());
+
+    assert!(decode_hex("abc").is_err());
+    assert!(decode_hex("zz").is_err());
+}
@@ -24,6 +24,12 @@ pub struct PopRequest {
     #[prost(string, tag = "1")]
     pub list_id: ::prost::alloc::string::String,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    #[prost(string, tag = "1")]
+    pub list_id: ::prost::alloc::string::String,
+}
 /// Generated client implementations.
 pub mod queue_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -144,6 +150,26 @@ pub mod queue_client {
             let path = http::uri::PathAndQuery::from_static("/queue.Queue/Pop");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::Node>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/queue.Queue/Subscribe");
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -165,6 +191,16 @@ pub mod queue_server {
             &self,
             request: tonic::Request<super::PopRequest>,
         ) -> Result<tonic::Response<super::Node>, tonic::Status>;
+        ///Server streaming response type for the Subscribe method.
+        type SubscribeStream: futures_core::Stream<
+                Item = Result<super::Node, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
     }
     #[derive(Debug)]
     pub struct QueueServer<T: Queue> {
@@ -329,6 +365,45 @@ pub mod queue_server {
                     };
                     Box::pin(fut)
                 }
+                "/queue.Queue/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: Queue>(pub Arc<T>);
+                    impl<
+                        T: Queue,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::Node;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).subscribe(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -0,0 +1,253 @@
+// tonic::Status is large but it is what every handler returns anyway.
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard};
+
+use futures_util::{stream, Stream};
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::sync::watch;
+use tonic::{Request, Response, Status};
+
+use super::queue::queue_server::Queue;
+use super::queue::{self, Node, PopRequest, PushRequest, SubscribeRequest};
+use super::wal::{Fsync, Op, Record, Wal};
+use crate::list::{AsyncList, List, Methods};
+
+type Lists = HashMap<String, Arc<AsyncList<Node>>>;
+
+/// Implementation of the `Queue` service where every list is a
+/// `SyncFifo` that subscribers can wait on.
+///
+/// Lists only live in memory unless the service is opened on a
+/// write-ahead log, see `QueueService::open`.
+#[derive(Default)]
+pub struct QueueService {
+    lists: RwLock<Lists>,
+    log: Log,
+    shutdown: Shutdown,
+}
+
+impl QueueService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Durable service which logs every change to `path` and starts
+    /// with whatever lists the log already had.
+    pub fn open(path: impl AsRef<Path>, fsync: Fsync) -> io::Result<Self> {
+        let (wal, records) = Wal::open(path, fsync)?;
+        Ok(Self::with_wal(wal, records))
+    }
+
+    /// Durable service on a log which is already open, `records` are
+    /// the ones read from it.
+    pub fn with_wal(wal: Wal, records: Vec<Record>) -> Self {
+        let svc = QueueService {
+            lists: RwLock::default(),
+            log: Log(Some(Arc::new(Mutex::new(wal)))),
+            shutdown: Shutdown::default(),
+        };
+        records
+            .into_iter()
+            .filter_map(|r| r.op)
+            .for_each(|op| svc.replay(op));
+        svc
+    }
+
+    /// Ends every subscription, open or yet to come, so a server
+    /// shutting down doesn't wait on subscribers forever.
+    pub fn shutdown(&self) {
+        self.shutdown.0.send_replace(true);
+    }
+
+    fn lists(&self) -> RwLockReadGuard<'_, Lists> {
+        self.lists.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn insert(&self, id: String) {
+        self.lists
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(id, Arc::new(List::waiting(List::sync_fifo())));
+    }
+
+    // applies a logged change without logging it again.
+    fn replay(&self, op: Op) {
+        match op {
+            Op::Create(id) => self.insert(id),
+            Op::Push(PushRequest {
+                list_id,
+                node: Some(node),
+            }) => {
+                if let Some(list) = self.lists().get(&list_id) {
+                    list.push(node);
+                };
+            }
+            Op::Push(_) => {}
+            Op::Pop(list_id) => {
+                if let Some(list) = self.lists().get(&list_id) {
+                    list.pop();
+                };
+            }
+        };
+    }
+}
+
+// Shared handle on the write-ahead log, if there is one.
+#[derive(Default, Clone)]
+struct Log(Option<Arc<Mutex<Wal>>>);
+
+impl Log {
+    // Every change is logged and applied while holding this lock, so
+    // the log has changes in the same order as the lists. It is always
+    // taken before `lists`.
+    fn lock(&self) -> LogGuard<'_> {
+        LogGuard(
+            self.0
+                .as_ref()
+                .map(|wal| blocking(|| wal.lock().unwrap_or_else(|e| e.into_inner()))),
+        )
+    }
+}
+
+// Writing to the log, or waiting on whoever is, blocks on disk. Where
+// the runtime allows it the worker hands its other tasks over to
+// another thread first, a current thread runtime has no other thread
+// so there it just blocks.
+fn blocking<R>(f: impl FnOnce() -> R) -> R {
+    match Handle::try_current().map(|h| h.runtime_flavor()) {
+        Ok(RuntimeFlavor::MultiThread) => tokio::task::block_in_place(f),
+        _ => f(),
+    }
+}
+
+struct LogGuard<'a>(Option<MutexGuard<'a, Wal>>);
+
+impl LogGuard<'_> {
+    fn append(&mut self, op: Op) -> Result<(), Status> {
+        if let Some(wal) = &mut self.0 {
+            blocking(|| wal.append(op))
+                .map_err(|e| Status::internal(format!("failed to write log: {}", e)))?;
+        };
+        Ok(())
+    }
+}
+
+// Fired once by `QueueService::shutdown`.
+struct Shutdown(watch::Sender<bool>);
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Shutdown(watch::channel(false).0)
+    }
+}
+
+/// Stream of nodes sent to a subscriber.
+pub type Subscription = Pin<Box<dyn Stream<Item = Result<Node, Status>> + Send>>;
+
+fn unknown_list(id: &str) -> Status {
+    Status::not_found(format!("list '{}' does not exist", id))
+}
+
+fn empty_list(id: &str) -> Status {
+    Status::failed_precondition(format!("list '{}' is empty", id))
+}
+
+// Pops are logged before the node is taken off the list, so a node
+// is never lost when the log can't be written. `None` when the list
+// is empty.
+fn pop_logged(log: &Log, list: &AsyncList<Node>, list_id: &str) -> Option<Result<Node, Status>> {
+    let mut log = log.lock();
+    if list.is_empty() {
+        return None;
+    };
+    if let Err(e) = log.append(Op::Pop(list_id.to_string())) {
+        return Some(Err(e));
+    };
+    // only a service without a log can lose a race for the node here
+    list.pop().map(Ok)
+}
+
+#[tonic::async_trait]
+impl Queue for QueueService {
+    async fn create(&self, _request: Request<()>) -> Result<Response<queue::List>, Status> {
+        let id = uuid::Uuid::new_v4().to_string();
+
+        let mut log = self.log.lock();
+        log.append(Op::Create(id.clone()))?;
+        self.insert(id.clone());
+
+        Ok(Response::new(queue::List { id }))
+    }
+
+    async fn push(&self, request: Request<PushRequest>) -> Result<Response<()>, Status> {
+        let PushRequest { list_id, node } = request.into_inner();
+        let node = node.ok_or_else(|| Status::invalid_argument("node is required"))?;
+
+        let mut log = self.log.lock();
+        let lists = self.lists();
+        let list = lists.get(&list_id).ok_or_else(|| unknown_list(&list_id))?;
+
+        log.append(Op::Push(PushRequest {
+            list_id: list_id.clone(),
+            node: Some(node.clone()),
+        }))?;
+        list.push(node);
+
+        Ok(Response::new(()))
+    }
+
+    async fn pop(&self, request: Request<PopRequest>) -> Result<Response<Node>, Status> {
+        let list_id = request.into_inner().list_id;
+
+        let list = self
+            .lists()
+            .get(&list_id)
+            .ok_or_else(|| unknown_list(&list_id))?
+            .clone();
+
+        match pop_logged(&self.log, &list, &list_id) {
+            Some(node) => node.map(Response::new),
+            None => Err(empty_list(&list_id)),
+        }
+    }
+
+    type SubscribeStream = Subscription;
+
+    async fn subscribe(
+        &self,
+        request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let list_id = request.into_inner().list_id;
+
+        let lists = self.lists();
+        let list = lists
+            .get(&list_id)
+            .ok_or_else(|| unknown_list(&list_id))?
+            .clone();
+        let log = self.log.clone();
+        let shutdown = self.shutdown.0.subscribe();
+
+        // nodes are only popped when tonic polls for the next one, so a
+        // subscriber that went away never takes a node off the list. A
+        // pending `pop_wait` dropped after being woken up passes the
+        // wake up on to the next subscriber. The stream ends once the
+        // service shuts down.
+        let nodes = stream::unfold((list, shutdown), move |(list, mut shutdown)| {
+            let (log, list_id) = (log.clone(), list_id.clone());
+            async move {
+                let res = tokio::select! {
+                    biased;
+                    _ = shutdown.wait_for(|stop| *stop) => return None,
+                    res = list.pop_wait_by(|| pop_logged(&log, &list, &list_id)) => res,
+                };
+                Some((res, (list, shutdown)))
+            }
+        });
+        Ok(Response::new(Box::pin(nodes)))
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tonic::{Code, Request};
+
+use super::queue::queue_server::Queue;
+use super::queue::{Node, PopRequest, PushRequest, SubscribeRequest};
+use super::service::{QueueService, Subscription};
+
+fn node(value: &[u8]) -> Node {
+    Node {
+        value: Some(prost_types::Any {
+            type_url: "test".to_string(),
+            value: value.to_vec(),
+        }),
+    }
+}
+
+async fn push(svc: &QueueService, list_id: &str, n: Node) -> Result<(), tonic::Status> {
+    svc.push(Request::new(PushRequest {
+        list_id: list_id.to_string(),
+        node: Some(n),
+    }))
+    .await
+    .map(|r| r.into_inner())
+}
+
+async fn pop(svc: &QueueService, list_id: &str) -> Result<Node, tonic::Status> {
+    svc.pop(Request::new(PopRequest {
+        list_id: list_id.to_string(),
+    }))
+    .await
+    .map(|r| r.into_inner())
+}
+
+async fn subscribe(svc: &QueueService, list_id: &str) -> Result<Subscription, tonic::Status> {
+    svc.subscribe(Request::new(SubscribeRequest {
+        list_id: list_id.to_string(),
+    }))
+    .await
+    .map(|r| r.into_inner())
+}
+
+// next node from the subscription, `None` if nothing arrives in time.
+async fn next(sub: &mut Subscription) -> Option<Node> {
+    tokio::time::timeout(Duration::from_millis(50), sub.next())
+        .await
+        .ok()
+        .flatten()
+        .map(|n| n.unwrap())
+}
+
+#[tokio::test]
+async fn test_service_create_returns_unique_ids() {
+    let svc = QueueService::new();
+
+    let l1 = svc.create(Request::new(())).await.unwrap().into_inner();
+    let l2 = svc.create(Request::new(())).await.unwrap().into_inner();
+
+    assert!(!l1.id.is_empty());
+    assert_ne!(l1.id, l2.id);
+}
+
+#[tokio::test]
+async fn test_service_push_and_pop_in_order() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    push(&svc, &id, node(b"1")).await.unwrap();
+    push(&svc, &id, node(b"2")).await.unwrap();
+
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"1"));
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"2"));
+}
+
+#[tokio::test]
+async fn test_service_lists_are_independent() {
+    let svc = QueueService::new();
+    let l1 = svc.create(Request::new(())).await.unwrap().into_inner().id;
+    let l2 = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    push(&svc, &l1, node(b"1")).await.unwrap();
+
+    assert_eq!(
+        pop(&svc, &l2).await.unwrap_err().code(),
+        Code::FailedPrecondition
+    );
+    assert_eq!(pop(&svc, &l1).await.unwrap(), node(b"1"));
+}
+
+#[tokio::test]
+async fn test_service_pop_empty() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    let err = pop(&svc, &id).await.unwrap_err();
+    assert_eq!(err.code(), Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn test_service_unknown_list() {
+    let svc = QueueService::new();
+
+    let err = push(&svc, "nope", node(b"1")).await.unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+
+    let err = pop(&svc, "nope").await.unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_service_push_without_node() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    let err = svc
+        .push(Request::new(PushRequest {
+            list_id: id,
+            node: None,
+        }))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_service_subscribe_receives_pushed_nodes() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    push(&svc, &id, node(b"1")).await.unwrap();
+    let mut sub = subscribe(&svc, &id).await.unwrap();
+
+    assert_eq!(next(&mut sub).await, Some(node(b"1")));
+    assert_eq!(next(&mut sub).await, None);
+
+    push(&svc, &id, node(b"2")).await.unwrap();
+    push(&svc, &id, node(b"3")).await.unwrap();
+    assert_eq!(next(&mut sub).await, Some(node(b"2")));
+    assert_eq!(next(&mut sub).await, Some(node(b"3")));
+
+    // delivered nodes are gone from the list
+    assert_eq!(
+        pop(&svc, &id).await.unwrap_err().code(),
+        Code::FailedPrecondition
+    );
+}
+
+#[tokio::test]
+async fn test_service_subscribe_waits_for_push() {
+    let svc = std::sync::Arc::new(QueueService::new());
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+    let mut sub = subscribe(&svc, &id).await.unwrap();
+
+    let pusher = {
+        let (svc, id) = (svc.clone(), id.clone());
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            push(&svc, &id, node(b"1")).await.unwrap();
+        })
+    };
+
+    assert_eq!(sub.next().await.unwrap().unwrap(), node(b"1"));
+    pusher.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_service_subscribe_delivers_each_node_once() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+    let mut s1 = subscribe(&svc, &id).await.unwrap();
+    let mut s2 = subscribe(&svc, &id).await.unwrap();
+
+    for i in 0..10u8 {
+        push(&svc, &id, node(&[i])).await.unwrap();
+    }
+
+    let mut received = vec![];
+    loop {
+        let (n1, n2) = (next(&mut s1).await, next(&mut s2).await);
+        if n1.is_none() && n2.is_none() {
+            break;
+        };
+        received.extend(n1.into_iter().chain(n2));
+    }
+
+    let mut values: Vec<u8> = received
+        .into_iter()
+        .map(|n| n.value.unwrap().value[0])
+        .collect();
+    values.sort();
+    assert_eq!(values, (0..10).collect::<Vec<u8>>());
+}
+
+#[tokio::test]
+async fn test_service_subscribe_disconnect_keeps_nodes() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+
+    // subscriber goes away while waiting for a node
+    let mut gone = subscribe(&svc, &id).await.unwrap();
+    assert_eq!(next(&mut gone).await, None);
+    drop(gone);
+
+    let mut sub = subscribe(&svc, &id).await.unwrap();
+    push(&svc, &id, node(b"1")).await.unwrap();
+    assert_eq!(next(&mut sub).await, Some(node(b"1")));
+
+    drop(sub);
+    push(&svc, &id, node(b"2")).await.unwrap();
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"2"));
+}
+
+#[tokio::test]
+async fn test_service_subscribe_unknown_list() {
+    let svc = QueueService::new();
+
+    let err = subscribe(&svc, "nope").await.err().unwrap();
+    assert_eq!(err.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_service_shutdown_ends_subscriptions() {
+    let svc = QueueService::new();
+    let id = svc.create(Request::new(())).await.unwrap().into_inner().id;
+    push(&svc, &id, node(b"1")).await.unwrap();
+
+    let mut sub = subscribe(&svc, &id).await.unwrap();
+    assert_eq!(next(&mut sub).await, Some(node(b"1")));
+
+    // shuts down while the subscriber waits for the next node
+    let (_, end) = tokio::join!(
+        async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            svc.shutdown();
+        },
+        tokio::time::timeout(Duration::from_secs(1), sub.next()),
+    );
+    assert!(end.unwrap().is_none());
+
+    // later subscriptions end straight away and leave the nodes alone
+    push(&svc, &id, node(b"2")).await.unwrap();
+    let mut late = subscribe(&svc, &id).await.unwrap();
+    assert!(late.next().await.is_none());
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"2"));
+}
@@ -0,0 +1,256 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use prost::Message;
+
+use super::queue::PushRequest;
+use crate::list::snapshot::crc32;
+
+// length and checksum in front of every record.
+const HEADER_LEN: usize = 8;
+
+/// One change to the queue contents, as written to the log.
+///
+/// Every record is prost encoded and framed as
+///
+/// ```text
+///   length   u32  little endian, size of the encoded record
+///   checksum u32  little endian, crc32 of the encoded record
+///   record   `length` bytes
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, Message)]
+pub struct Record {
+    #[prost(oneof = "Op", tags = "1, 2, 3")]
+    pub op: Option<Op>,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Oneof)]
+pub enum Op {
+    /// id of the new list.
+    #[prost(string, tag = "1")]
+    Create(String),
+    #[prost(message, tag = "2")]
+    Push(PushRequest),
+    /// id of the list a node was popped from.
+    #[prost(string, tag = "3")]
+    Pop(String),
+}
+
+/// When the log is flushed to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fsync {
+    /// after every record, nothing acknowledged is ever lost.
+    Always,
+    /// after every `n` records.
+    Every(usize),
+    /// never, it's up to the OS.
+    Never,
+}
+
+impl Display for Fsync {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Fsync::Always => write!(fmt, "always"),
+            Fsync::Every(n) => write!(fmt, "{}", n),
+            Fsync::Never => write!(fmt, "never"),
+        }
+    }
+}
+
+// parses `always`, `never` or a number of records.
+impl FromStr for Fsync {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Fsync::Always),
+            "never" => Ok(Fsync::Never),
+            n => match n.parse::<usize>() {
+                Ok(0) => Err("fsync interval must be at least 1".to_string()),
+                Ok(n) => Ok(Fsync::Every(n)),
+                Err(_) => Err(format!(
+                    "invalid fsync policy '{}', expected always, never or a number",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+/// Append only log of queue changes.
+pub struct Wal {
+    file: File,
+    fsync: Fsync,
+    // records written since the last fsync
+    unsynced: usize,
+    // where the last good record ends
+    len: u64,
+    // set once a failed record couldn't be taken back out
+    broken: bool,
+    // cuts the next record short after this many bytes
+    #[cfg(test)]
+    pub(crate) fail_after: Option<usize>,
+}
+
+impl Wal {
+    /// Opens the log at `path`, creating it if needed, and returns it
+    /// along with the records already in it.
+    ///
+    /// A record cut short by a crash while it was being written is
+    /// dropped from the end of the log. A damaged record anywhere else
+    /// fails with `InvalidData` rather than losing the ones after it.
+    pub fn open(path: impl AsRef<Path>, fsync: Fsync) -> io::Result<(Wal, Vec<Record>)> {
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)?;
+        Self::from_file(file, fsync)
+    }
+
+    /// Like `open` on a file which is already open, new records are
+    /// written at its end.
+    pub fn from_file(mut file: File, fsync: Fsync) -> io::Result<(Wal, Vec<Record>)> {
+        let mut data = vec![];
+        file.read_to_end(&mut data)?;
+
+        let mut records = vec![];
+        let mut offset = 0;
+        while offset < data.len() {
+            match decode(&data[offset..]) {
+                Frame::Record(record, len) => {
+                    records.push(record);
+                    offset += len;
+                }
+                Frame::Torn => {
+                    file.set_len(offset as u64)?;
+                    file.seek(SeekFrom::Start(offset as u64))?;
+                    file.sync_data()?;
+                    break;
+                }
+                Frame::Corrupt => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("log record at offset {} is corrupted", offset),
+                    ))
+                }
+            };
+        }
+
+        let wal = Wal {
+            file,
+            fsync,
+            unsynced: 0,
+            len: offset as u64,
+            broken: false,
+            #[cfg(test)]
+            fail_after: None,
+        };
+        Ok((wal, records))
+    }
+
+    /// Writes a record, which is only in the log once this returns
+    /// `Ok`. A record that fails half way is taken back out so later
+    /// ones don't end up behind it.
+    pub fn append(&mut self, op: Op) -> io::Result<()> {
+        if self.broken {
+            return Err(io::Error::other("log is unusable after a failed write"));
+        };
+
+        let record = Record { op: Some(op) }.encode_to_vec();
+        let mut buf = Vec::with_capacity(HEADER_LEN + record.len());
+        buf.extend_from_slice(&(record.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&crc32(&record).to_le_bytes());
+        buf.extend_from_slice(&record);
+
+        if let Err(e) = self.write(&buf) {
+            self.rollback();
+            return Err(e);
+        };
+        self.len += buf.len() as u64;
+        Ok(())
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        #[cfg(test)]
+        if let Some(n) = self.fail_after.take() {
+            self.file.write_all(&buf[..n.min(buf.len())])?;
+            return Err(io::Error::other("injected write failure"));
+        };
+
+        self.file.write_all(buf)?;
+        self.unsynced += 1;
+        let sync = match self.fsync {
+            Fsync::Always => true,
+            Fsync::Every(n) => self.unsynced >= n,
+            Fsync::Never => false,
+        };
+        if sync {
+            self.sync()?;
+        };
+        Ok(())
+    }
+
+    // cuts the log back to the last good record, if even that fails
+    // no more records are taken.
+    fn rollback(&mut self) {
+        let res = self
+            .file
+            .set_len(self.len)
+            .and_then(|_| self.file.seek(SeekFrom::Start(self.len)));
+        if res.is_err() {
+            self.broken = true;
+        };
+    }
+
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.file.sync_data()?;
+        self.unsynced = 0;
+        Ok(())
+    }
+}
+
+// What was found at some offset of the log.
+enum Frame {
+    // a good record and how many bytes it took, header included.
+    Record(Record, usize),
+    // the last record, cut short.
+    Torn,
+    // a damaged record with more after it.
+    Corrupt,
+}
+
+fn decode(data: &[u8]) -> Frame {
+    if data.len() < HEADER_LEN {
+        return Frame::Torn;
+    };
+    let len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let checksum = u32::from_le_bytes(data[4..8].try_into().unwrap());
+    let end = HEADER_LEN + len;
+    if end > data.len() {
+        return Frame::Torn;
+    };
+
+    let body = &data[HEADER_LEN..end];
+    match Record::decode(body) {
+        Ok(record) if crc32(body) == checksum => Frame::Record(record, end),
+        // a crash can leave the last record with garbage in it
+        _ if end == data.len() => Frame::Torn,
+        _ => Frame::Corrupt,
+    }
+}
+
+// whatever the policy, records are on disk once the log is closed.
+impl Drop for Wal {
+    fn drop(&mut self) {
+        if self.unsynced > 0 {
+            let _ = self.sync();
+        };
+    }
+}
@@ -0,0 +1,370 @@
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use tonic::{Code, Request};
+
+use super::queue::queue_server::Queue;
+use super::queue::{Node, PopRequest, PushRequest, SubscribeRequest};
+use super::service::QueueService;
+use super::wal::{Fsync, Op, Wal};
+
+// log file in the temp dir, removed once the test is done.
+struct TempLog(PathBuf);
+
+impl TempLog {
+    fn new() -> Self {
+        let name = format!("queue-{}.wal", uuid::Uuid::new_v4());
+        TempLog(std::env::temp_dir().join(name))
+    }
+}
+
+impl Drop for TempLog {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn node(value: &[u8]) -> Node {
+    Node {
+        value: Some(prost_types::Any {
+            type_url: "test".to_string(),
+            value: value.to_vec(),
+        }),
+    }
+}
+
+async fn create(svc: &QueueService) -> String {
+    svc.create(Request::new(())).await.unwrap().into_inner().id
+}
+
+async fn push(svc: &QueueService, list_id: &str, n: Node) {
+    svc.push(Request::new(PushRequest {
+        list_id: list_id.to_string(),
+        node: Some(n),
+    }))
+    .await
+    .unwrap();
+}
+
+async fn pop(svc: &QueueService, list_id: &str) -> Result<Node, tonic::Status> {
+    svc.pop(Request::new(PopRequest {
+        list_id: list_id.to_string(),
+    }))
+    .await
+    .map(|r| r.into_inner())
+}
+
+#[test]
+fn test_fsync_from_str() {
+    assert_eq!("always".parse::<Fsync>(), Ok(Fsync::Always));
+    assert_eq!("never".parse::<Fsync>(), Ok(Fsync::Never));
+    assert_eq!("100".parse::<Fsync>(), Ok(Fsync::Every(100)));
+    assert!("0".parse::<Fsync>().is_err());
+    assert!("sometimes".parse::<Fsync>().is_err());
+}
+
+#[test]
+fn test_wal_reads_back_records() {
+    let path = TempLog::new();
+
+    let (mut wal, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    assert!(records.is_empty());
+    wal.append(Op::Create("l".to_string())).unwrap();
+    wal.append(Op::Push(PushRequest {
+        list_id: "l".to_string(),
+        node: Some(node(b"1")),
+    }))
+    .unwrap();
+    wal.append(Op::Pop("l".to_string())).unwrap();
+    drop(wal);
+
+    let (_, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    let ops: Vec<Op> = records.into_iter().filter_map(|r| r.op).collect();
+    assert_eq!(
+        ops,
+        vec![
+            Op::Create("l".to_string()),
+            Op::Push(PushRequest {
+                list_id: "l".to_string(),
+                node: Some(node(b"1")),
+            }),
+            Op::Pop("l".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_wal_drops_torn_record() {
+    let path = TempLog::new();
+
+    let (mut wal, _) = Wal::open(&path.0, Fsync::Always).unwrap();
+    wal.append(Op::Create("l".to_string())).unwrap();
+    drop(wal);
+
+    // a record whose length says more bytes than were written
+    let mut file = OpenOptions::new().append(true).open(&path.0).unwrap();
+    file.write_all(&[20, 10, 3]).unwrap();
+    drop(file);
+
+    let (mut wal, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    assert_eq!(records.len(), 1);
+
+    // new records go where the torn one was
+    wal.append(Op::Create("m".to_string())).unwrap();
+    drop(wal);
+
+    let (_, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    let ops: Vec<Op> = records.into_iter().filter_map(|r| r.op).collect();
+    assert_eq!(
+        ops,
+        vec![Op::Create("l".to_string()), Op::Create("m".to_string())]
+    );
+}
+
+#[test]
+fn test_wal_takes_back_failed_append() {
+    let path = TempLog::new();
+
+    let (mut wal, _) = Wal::open(&path.0, Fsync::Always).unwrap();
+    wal.append(Op::Create("a".to_string())).unwrap();
+    // only part of the record makes it to the file
+    wal.fail_after = Some(5);
+    assert!(wal.append(Op::Create("b".to_string())).is_err());
+    wal.append(Op::Create("c".to_string())).unwrap();
+    drop(wal);
+
+    let (_, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    let ops: Vec<Op> = records.into_iter().filter_map(|r| r.op).collect();
+    assert_eq!(
+        ops,
+        vec![Op::Create("a".to_string()), Op::Create("c".to_string())]
+    );
+}
+
+// log with three records, and the offset each one starts at.
+fn three_records(path: &TempLog) -> Vec<u64> {
+    let (mut wal, _) = Wal::open(&path.0, Fsync::Always).unwrap();
+    let mut offsets = vec![];
+    for id in ["a", "b", "c"] {
+        offsets.push(std::fs::metadata(&path.0).unwrap().len());
+        wal.append(Op::Create(id.to_string())).unwrap();
+    }
+    offsets
+}
+
+// flips a bit of the byte at `offset`.
+fn damage(path: &TempLog, offset: u64) {
+    let mut data = std::fs::read(&path.0).unwrap();
+    data[offset as usize] ^= 0x40;
+    std::fs::write(&path.0, data).unwrap();
+}
+
+#[test]
+fn test_wal_rejects_damaged_record() {
+    let path = TempLog::new();
+    let offsets = three_records(&path);
+    let len = std::fs::metadata(&path.0).unwrap().len();
+
+    // last byte of the second record
+    damage(&path, offsets[2] - 1);
+    let err = Wal::open(&path.0, Fsync::Always).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    // nothing was cut off
+    assert_eq!(std::fs::metadata(&path.0).unwrap().len(), len);
+}
+
+#[test]
+fn test_wal_drops_damaged_last_record() {
+    let path = TempLog::new();
+    let offsets = three_records(&path);
+
+    damage(&path, offsets[2] + 9);
+    let (_, records) = Wal::open(&path.0, Fsync::Always).unwrap();
+    let ops: Vec<Op> = records.into_iter().filter_map(|r| r.op).collect();
+    assert_eq!(
+        ops,
+        vec![Op::Create("a".to_string()), Op::Create("b".to_string())]
+    );
+    assert_eq!(std::fs::metadata(&path.0).unwrap().len(), offsets[2]);
+}
+
+#[tokio::test]
+async fn test_service_restart_keeps_lists() {
+    let path = TempLog::new();
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    let l1 = create(&svc).await;
+    let l2 = create(&svc).await;
+    push(&svc, &l1, node(b"1")).await;
+    push(&svc, &l1, node(b"2")).await;
+    push(&svc, &l1, node(b"3")).await;
+    push(&svc, &l2, node(b"a")).await;
+    assert_eq!(pop(&svc, &l1).await.unwrap(), node(b"1"));
+    drop(svc);
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    assert_eq!(pop(&svc, &l1).await.unwrap(), node(b"2"));
+    push(&svc, &l1, node(b"4")).await;
+    drop(svc);
+
+    let svc = QueueService::open(&path.0, Fsync::Every(100)).unwrap();
+    assert_eq!(pop(&svc, &l1).await.unwrap(), node(b"3"));
+    assert_eq!(pop(&svc, &l1).await.unwrap(), node(b"4"));
+    assert_eq!(
+        pop(&svc, &l1).await.unwrap_err().code(),
+        Code::FailedPrecondition
+    );
+    assert_eq!(pop(&svc, &l2).await.unwrap(), node(b"a"));
+}
+
+#[tokio::test]
+async fn test_service_restart_after_subscribe() {
+    let path = TempLog::new();
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    let id = create(&svc).await;
+    push(&svc, &id, node(b"1")).await;
+    push(&svc, &id, node(b"2")).await;
+
+    let mut sub = svc
+        .subscribe(Request::new(SubscribeRequest {
+            list_id: id.clone(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(sub.next().await.unwrap().unwrap(), node(b"1"));
+    drop(sub);
+    drop(svc);
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"2"));
+}
+
+#[tokio::test]
+async fn test_service_crash_keeps_acknowledged_changes() {
+    let path = TempLog::new();
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    let id = create(&svc).await;
+    push(&svc, &id, node(b"1")).await;
+    push(&svc, &id, node(b"2")).await;
+    push(&svc, &id, node(b"3")).await;
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"1"));
+    // no drop, so nothing gets synced on the way out
+    std::mem::forget(svc);
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"2"));
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"3"));
+    assert_eq!(
+        pop(&svc, &id).await.unwrap_err().code(),
+        Code::FailedPrecondition
+    );
+}
+
+#[tokio::test]
+async fn test_service_keeps_node_when_log_fails() {
+    let path = TempLog::new();
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    let id = create(&svc).await;
+    push(&svc, &id, node(b"1")).await;
+    drop(svc);
+
+    // a read only log fails every write
+    let file = File::open(&path.0).unwrap();
+    let (wal, records) = Wal::from_file(file, Fsync::Always).unwrap();
+    let svc = QueueService::with_wal(wal, records);
+
+    assert_eq!(pop(&svc, &id).await.unwrap_err().code(), Code::Internal);
+    let mut sub = svc
+        .subscribe(Request::new(SubscribeRequest {
+            list_id: id.clone(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(
+        sub.next().await.unwrap().unwrap_err().code(),
+        Code::Internal
+    );
+    drop(sub);
+    // still there, an empty list would be a failed precondition
+    assert_eq!(pop(&svc, &id).await.unwrap_err().code(), Code::Internal);
+    drop(svc);
+
+    let svc = QueueService::open(&path.0, Fsync::Always).unwrap();
+    assert_eq!(pop(&svc, &id).await.unwrap(), node(b"1"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_service_concurrent_create_and_push() {
+    let path = TempLog::new();
+    let svc = Arc::new(QueueService::open(&path.0, Fsync::Never).unwrap());
+    let id = create(&svc).await;
+
+    let tasks: Vec<_> = (0..4)
+        .map(|task| {
+            let (svc, id) = (svc.clone(), id.clone());
+            tokio::spawn(async move {
+                for _ in 0..200 {
+                    match task % 2 {
+                        0 => drop(create(&svc).await),
+                        _ => push(&svc, &id, node(b"1")).await,
+                    };
+                }
+            })
+        })
+        .collect();
+
+    // hangs rather than fails if the handlers take their locks in a
+    // different order, every worker ends up blocked.
+    for task in futures_util::future::join_all(tasks).await {
+        task.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_service_log_writes_leave_the_runtime_running() {
+    let path = TempLog::new();
+    let svc = Arc::new(QueueService::open(&path.0, Fsync::Always).unwrap());
+    let id = create(&svc).await;
+
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let ticker = {
+        let ticks = ticks.clone();
+        tokio::spawn(async move {
+            loop {
+                ticks.fetch_add(1, Ordering::Relaxed);
+                tokio::task::yield_now().await;
+            }
+        })
+    };
+
+    // the pushes never yield, so on the only worker the ticker can
+    // only run while the log is written from another thread.
+    let pushes = tokio::spawn(async move {
+        let start = ticks.load(Ordering::Relaxed);
+        for _ in 0..50 {
+            push(&svc, &id, node(b"1")).await;
+        }
+        ticks.load(Ordering::Relaxed) - start
+    });
+    assert!(pushes.await.unwrap() > 0);
+    ticker.abort();
+}
+
+#[tokio::test]
+async fn test_service_without_log_forgets_lists() {
+    let svc = QueueService::new();
+    let id = create(&svc).await;
+    drop(svc);
+
+    let svc = QueueService::new();
+    assert_eq!(pop(&svc, &id).await.unwrap_err().code(), Code::NotFound);
+}
@@ -1,3 +1,3 @@
 pub mod list;
 
-pub(crate) mod grpcd;
+pub mod grpcd;
@@ -0,0 +1,381 @@
+use std::cell::{Cell, RefCell};
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+
+use super::list::{List, Methods};
+use super::Node;
+
+// Doubly linked node. Only the forward links own the next node,
+// going backwards goes through `Weak` so there are no cycles.
+struct DequeNode<T> {
+    value: T,
+    next: DequeLink<T>,
+    prev: RefCell<Weak<DequeNode<T>>>,
+}
+
+// Forward link, same as `Link` it drops the rest of the chain one
+// node at a time so long deques don't overflow the stack. That holds
+// for whoever lets go of the chain last, the deque or an iterator.
+struct DequeLink<T>(RefCell<Option<Rc<DequeNode<T>>>>);
+
+impl<T> Deref for DequeLink<T> {
+    type Target = RefCell<Option<Rc<DequeNode<T>>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Drop for DequeLink<T> {
+    fn drop(&mut self) {
+        let mut next = self.0.take();
+        while let Some(node) = next {
+            next = match Rc::try_unwrap(node) {
+                Ok(node) => node.next.take(),
+                // still used by someone else, they drop it later on
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+impl<T> DequeNode<T> {
+    fn new(value: T) -> Rc<Self> {
+        Rc::new(DequeNode {
+            value,
+            next: DequeLink(RefCell::new(None)),
+            prev: RefCell::new(Weak::new()),
+        })
+    }
+
+    fn next(&self) -> Option<Rc<DequeNode<T>>> {
+        self.next.borrow().clone()
+    }
+
+    fn prev(&self) -> Option<Rc<DequeNode<T>>> {
+        self.prev.borrow().upgrade()
+    }
+}
+
+impl<T> DequeNode<T>
+where
+    T: Clone,
+{
+    // moves the value out when nobody else holds the node.
+    fn take_value(node: Rc<Self>) -> T {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.value,
+            Err(node) => node.value.clone(),
+        }
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + PartialEq,
+{
+    // deque where `push` and `pop` behave like a `Fifo`.
+    pub fn deque() -> Box<Deque<T>> {
+        Box::new(Deque::new(false))
+    }
+
+    // deque where `push` and `pop` behave like a `Lifo`.
+    pub fn deque_lifo() -> Box<Deque<T>> {
+        Box::new(Deque::new(true))
+    }
+}
+
+// double ended queue with constant time push and pop on both ends.
+pub struct Deque<T> {
+    first: RefCell<Option<Rc<DequeNode<T>>>>,
+    last: RefCell<Weak<DequeNode<T>>>,
+    len: Cell<usize>,
+    // whether `Methods::push` goes to the front
+    lifo: bool,
+}
+
+impl<T> Deque<T> {
+    fn new(lifo: bool) -> Self {
+        Deque {
+            first: RefCell::new(None),
+            last: RefCell::new(Weak::new()),
+            len: Cell::new(0),
+            lifo,
+        }
+    }
+
+    pub fn push_front(&self, val: T) {
+        let new = DequeNode::new(val);
+        match self.first.take() {
+            Some(first) => {
+                first.prev.replace(Rc::downgrade(&new));
+                new.next.replace(Some(first));
+            }
+            None => {
+                self.last.replace(Rc::downgrade(&new));
+            }
+        };
+        self.first.replace(Some(new));
+        self.len.set(self.len.get() + 1);
+    }
+
+    pub fn push_back(&self, val: T) {
+        let new = DequeNode::new(val);
+        let last = self.last.borrow().upgrade();
+        match last {
+            Some(last) => {
+                new.prev.replace(Rc::downgrade(&last));
+                last.next.replace(Some(new.clone()));
+            }
+            None => {
+                self.first.replace(Some(new.clone()));
+            }
+        };
+        self.last.replace(Rc::downgrade(&new));
+        self.len.set(self.len.get() + 1);
+    }
+
+    // unlinks `node` from its neighbours and returns it.
+    fn unlink(&self, node: Rc<DequeNode<T>>) -> Rc<DequeNode<T>> {
+        let prev = node.prev();
+        let next = node.next.take();
+
+        match &next {
+            Some(next) => {
+                next.prev.replace(node.prev.take());
+            }
+            None => {
+                self.last.replace(match &prev {
+                    Some(prev) => Rc::downgrade(prev),
+                    None => Weak::new(),
+                });
+            }
+        };
+        match prev {
+            Some(prev) => prev.next.replace(next),
+            None => self.first.replace(next),
+        };
+
+        self.len.set(self.len.get() - 1);
+        node
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> DequeIter<T> {
+        DequeIter {
+            front: self.first.borrow().clone(),
+            back: self.last.borrow().upgrade(),
+            remaining: self.len(),
+        }
+    }
+}
+
+impl<T> Deque<T>
+where
+    T: Clone,
+{
+    pub fn pop_front(&self) -> Option<T> {
+        let first = self.first.borrow().clone()?;
+        Some(DequeNode::take_value(self.unlink(first)))
+    }
+
+    pub fn pop_back(&self) -> Option<T> {
+        let last = self.last.borrow().upgrade()?;
+        Some(DequeNode::take_value(self.unlink(last)))
+    }
+
+    pub fn peek_front(&self) -> Option<T> {
+        self.first.borrow().as_ref().map(|n| n.value.clone())
+    }
+
+    pub fn peek_back(&self) -> Option<T> {
+        self.last.borrow().upgrade().map(|n| n.value.clone())
+    }
+}
+
+impl<T> Methods<T> for Deque<T>
+where
+    T: Clone + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    // builds a copy of the deque, front to back, as a `Node` chain.
+    fn head(&self) -> Rc<Node<T>> {
+        let mut head = Node::nil();
+        for val in self.iter().rev() {
+            let node = Node::new(val);
+            node.set_next(head);
+            head = node;
+        }
+        head
+    }
+
+    fn push(&self, val: T) {
+        match self.lifo {
+            true => self.push_front(val),
+            false => self.push_back(val),
+        }
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.peek_front()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.peek_back()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        let mut node = self.first.borrow().clone();
+        while let Some(n) = node {
+            if n.value == *val {
+                return true;
+            };
+            node = n.next();
+        }
+        false
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.remove_first_where(&mut |v| v == val).is_some()
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        let before = self.len();
+        self.retain(&mut |v| v != val);
+        before - self.len()
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        let mut node = self.first.borrow().clone();
+        while let Some(n) = node {
+            if pred(&n.value) {
+                return Some(DequeNode::take_value(self.unlink(n)));
+            };
+            node = n.next();
+        }
+        None
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        let mut node = self.first.borrow().clone();
+        while let Some(n) = node {
+            node = n.next();
+            if !keep(&n.value) {
+                self.unlink(n);
+            };
+        }
+    }
+}
+
+// Walks the deque from both ends, values are cloned. Stops once it
+// has returned as many values as the deque had when it was created.
+pub struct DequeIter<T> {
+    front: Option<Rc<DequeNode<T>>>,
+    back: Option<Rc<DequeNode<T>>>,
+    remaining: usize,
+}
+
+impl<T> Iterator for DequeIter<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        };
+        let node = self.front.take()?;
+        self.front = node.next();
+        self.remaining -= 1;
+        Some(node.value.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for DequeIter<T>
+where
+    T: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        };
+        let node = self.back.take()?;
+        self.back = node.prev();
+        self.remaining -= 1;
+        Some(node.value.clone())
+    }
+}
+
+// Consuming iterator, pops values off either end.
+pub struct DequeIntoIter<T> {
+    d: Deque<T>,
+}
+
+impl<T> Iterator for DequeIntoIter<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.d.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.d.len(), Some(self.d.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for DequeIntoIter<T>
+where
+    T: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.d.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for DequeIntoIter<T> where T: Clone {}
+
+impl<T> IntoIterator for Deque<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = DequeIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DequeIntoIter { d: self }
+    }
+}
+
+impl<T> IntoIterator for &Deque<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = DequeIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
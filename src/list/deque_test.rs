@@ -0,0 +1,164 @@
+use std::rc::Rc;
+
+use super::list::{List, Methods};
+
+#[test]
+fn test_deque_push_and_pop_both_ends() {
+    let d = List::deque();
+
+    d.push_back(2);
+    d.push_back(3);
+    d.push_front(1);
+    d.push_front(0);
+
+    assert_eq!(d.len(), 4);
+    assert_eq!(d.peek_front(), Some(0));
+    assert_eq!(d.peek_back(), Some(3));
+
+    assert_eq!(d.pop_back(), Some(3));
+    assert_eq!(d.pop_front(), Some(0));
+    assert_eq!(d.pop_back(), Some(2));
+    assert_eq!(d.pop_back(), Some(1));
+    assert_eq!(d.pop_back(), None);
+    assert_eq!(d.pop_front(), None);
+    assert_eq!(d.peek_front(), None);
+    assert_eq!(d.peek_back(), None);
+    assert!(d.is_empty());
+
+    // ends are still linked up after draining
+    d.push_front(5);
+    assert_eq!(d.peek_back(), Some(5));
+    d.push_back(6);
+    assert_eq!(d.pop_front(), Some(5));
+    assert_eq!(d.pop_front(), Some(6));
+}
+
+#[test]
+fn test_deque_stands_in_for_fifo_and_lifo() {
+    type Pair = (Box<dyn Methods<u8>>, Box<dyn Methods<u8>>);
+    let lists: Vec<Pair> = vec![
+        (List::fifo(), List::deque()),
+        (List::lifo(), List::deque_lifo()),
+    ];
+
+    for (list, deque) in lists {
+        for val in [1, 5, 2, 3] {
+            list.push(val);
+            deque.push(val);
+        }
+        assert_eq!(list.to_vec(), deque.to_vec());
+        assert!(list.remove(&5) && deque.remove(&5));
+
+        while let Some(val) = list.pop() {
+            assert_eq!(deque.pop(), Some(val));
+        }
+        assert_eq!(deque.pop(), None);
+    }
+}
+
+#[test]
+fn test_deque_remove_and_contains() {
+    let d = List::deque();
+    for val in 0..5 {
+        d.push_back(val);
+    }
+
+    assert!(d.contains(&4));
+    assert!(d.remove(&4));
+    assert!(!d.contains(&4));
+    assert_eq!(d.peek_back(), Some(3));
+
+    assert!(d.remove(&0));
+    assert_eq!(d.peek_front(), Some(1));
+
+    assert!(d.remove(&2));
+    assert!(!d.remove(&2));
+    assert_eq!(d.len(), 2);
+    assert_eq!(d.iter().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(d.iter().rev().collect::<Vec<_>>(), vec![3, 1]);
+}
+
+#[test]
+fn test_deque_iter_both_directions() {
+    let d = List::deque();
+    for val in 1..=4 {
+        d.push_back(val);
+    }
+
+    let mut iter = d.iter();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut seen = vec![];
+    for val in d.as_ref() {
+        seen.push(val);
+    }
+    assert_eq!(seen, vec![1, 2, 3, 4]);
+    assert_eq!(d.len(), 4);
+}
+
+#[test]
+fn test_deque_into_iter() {
+    let d = List::deque();
+    for val in 1..=4 {
+        d.push_back(val.to_string());
+    }
+
+    let mut iter = d.into_iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back().as_deref(), Some("4"));
+    assert_eq!(iter.next().as_deref(), Some("1"));
+    assert_eq!(iter.collect::<Vec<_>>(), vec!["2", "3"]);
+}
+
+#[test]
+fn test_deque_values_are_moved_out() {
+    let d = List::deque();
+    let val = Rc::new(());
+
+    d.push_back(val.clone());
+    d.push_back(val.clone());
+    assert_eq!(Rc::strong_count(&val), 3);
+
+    drop(d.pop_back());
+    drop(d.pop_front());
+    assert_eq!(Rc::strong_count(&val), 1);
+}
+
+#[test]
+fn test_deque_has_no_cycles() {
+    let val = Rc::new(());
+    {
+        let d = List::deque();
+        for _ in 0..10 {
+            d.push_back(val.clone());
+        }
+        assert_eq!(Rc::strong_count(&val), 11);
+    }
+    // every node got freed along with the deque
+    assert_eq!(Rc::strong_count(&val), 1);
+}
+
+#[test]
+fn test_deque_drop_million() {
+    let d = List::deque();
+    for i in 0..1_000_000 {
+        d.push(i);
+    }
+    assert_eq!(d.len(), 1_000_000);
+    drop(d);
+
+    // the iterator ends up owning the whole chain
+    let d = List::deque();
+    for i in 0..1_000_000 {
+        d.push(i);
+    }
+    let mut iter = d.iter();
+    drop(d);
+    assert_eq!(iter.next(), Some(0));
+    drop(iter);
+}
@@ -0,0 +1,228 @@
+use std::fmt::{Debug, Display, Formatter};
+
+use super::deque::Deque;
+use super::list::{Fifo, Lifo, Methods, Ordered};
+use super::priority::Priority;
+use super::sync::{SyncFifo, SyncLifo, SyncOrdered};
+use super::wait::AsyncList;
+
+// How values are laid out by `ListFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    // [1, 2, 3]
+    Compact,
+    // 1 -> 2 -> 3 -> nil
+    Arrow,
+}
+
+// Options for printing a list, eg:
+//
+//   ListFormat::new().label(true).max(2).display(&l)
+//
+// prints `Fifo [1, 2, … 3 more]` for a fifo holding 1 to 5.
+#[derive(Debug, Clone, Copy)]
+pub struct ListFormat {
+    style: Style,
+    label: bool,
+    max: Option<usize>,
+}
+
+impl Default for ListFormat {
+    fn default() -> Self {
+        ListFormat {
+            style: Style::Compact,
+            label: false,
+            max: None,
+        }
+    }
+}
+
+impl ListFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn compact(self) -> Self {
+        self.style(Style::Compact)
+    }
+
+    pub fn arrow(self) -> Self {
+        self.style(Style::Arrow)
+    }
+
+    // prefix the values with the kind of list, eg: `Fifo`.
+    pub fn label(mut self, label: bool) -> Self {
+        self.label = label;
+        self
+    }
+
+    // print at most `max` values, the rest are only counted.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn display<'a, T>(&self, list: &'a dyn Methods<T>) -> DisplayList<'a, T>
+    where
+        T: Clone,
+    {
+        DisplayList { opts: *self, list }
+    }
+}
+
+// `Display` adapter returned by `ListFormat::display`.
+pub struct DisplayList<'a, T> {
+    opts: ListFormat,
+    list: &'a dyn Methods<T>,
+}
+
+impl<T> Display for DisplayList<'_, T>
+where
+    T: Clone + Display,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let ListFormat { style, label, max } = self.opts;
+        let len = self.list.len();
+        let shown = max.map_or(len, |max| max.min(len));
+
+        if label {
+            write!(fmt, "{} ", kind_name(self.list.impl_name()))?;
+        };
+
+        let sep = match style {
+            Style::Compact => ", ",
+            Style::Arrow => " -> ",
+        };
+        if style == Style::Compact {
+            fmt.write_str("[")?;
+        };
+        for (idx, val) in self.list.iter().take(shown).enumerate() {
+            if idx > 0 {
+                fmt.write_str(sep)?;
+            };
+            write!(fmt, "{}", val)?;
+        }
+
+        match (style, len - shown) {
+            (Style::Compact, 0) => fmt.write_str("]"),
+            (Style::Compact, more) if shown == 0 => write!(fmt, "… {} more]", more),
+            (Style::Compact, more) => write!(fmt, "{}… {} more]", sep, more),
+            (Style::Arrow, 0) if shown == 0 => fmt.write_str("nil"),
+            (Style::Arrow, 0) => write!(fmt, "{}nil", sep),
+            (Style::Arrow, more) if shown == 0 => write!(fmt, "… {} more", more),
+            (Style::Arrow, more) => write!(fmt, "{}… {} more", sep, more),
+        }
+    }
+}
+
+// `rust_exercises::list::list::Fifo<i32>` -> `Fifo`
+fn kind_name(type_name: &str) -> &str {
+    let name = type_name.split('<').next().unwrap_or(type_name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+impl<T> Display for dyn Methods<T>
+where
+    T: Clone + Display + 'static,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        ListFormat::new().label(true).display(self).fmt(fmt)
+    }
+}
+
+// every variant shows up as its kind plus its values, head first.
+fn debug_list<T>(fmt: &mut Formatter<'_>, list: &dyn Methods<T>) -> Result<(), std::fmt::Error>
+where
+    T: Clone + Debug,
+{
+    fmt.debug_tuple(kind_name(list.impl_name()))
+        .field(&list.to_vec())
+        .finish()
+}
+
+impl<T> Debug for Lifo<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for Fifo<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for Ordered<T>
+where
+    T: Clone + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for Priority<T>
+where
+    T: Clone + PartialOrd + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for Deque<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for SyncLifo<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for SyncFifo<T>
+where
+    T: Clone + PartialEq + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+impl<T> Debug for SyncOrdered<T>
+where
+    T: Clone + PartialOrd + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        debug_list(fmt, self)
+    }
+}
+
+// shows the values of the list it wraps.
+impl<T> Debug for AsyncList<T>
+where
+    T: Clone + Debug,
+{
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        fmt.debug_tuple("AsyncList").field(&self.to_vec()).finish()
+    }
+}
@@ -0,0 +1,117 @@
+use super::format::{ListFormat, Style};
+use super::list::{Fifo, Lifo, List, Methods, Ordered};
+
+#[test]
+fn test_format_compact() {
+    let l = Fifo::from(vec![1, 2, 3]);
+
+    assert_eq!(ListFormat::new().display(&l).to_string(), "[1, 2, 3]");
+    assert_eq!(
+        ListFormat::new().label(true).display(&l).to_string(),
+        "Fifo [1, 2, 3]"
+    );
+}
+
+#[test]
+fn test_format_arrow() {
+    let l = Lifo::from(vec![1, 2, 3]);
+
+    assert_eq!(
+        ListFormat::new().arrow().display(&l).to_string(),
+        "3 -> 2 -> 1 -> nil"
+    );
+    assert_eq!(
+        ListFormat::new()
+            .style(Style::Arrow)
+            .label(true)
+            .display(&l)
+            .to_string(),
+        "Lifo 3 -> 2 -> 1 -> nil"
+    );
+}
+
+#[test]
+fn test_format_empty() {
+    let l: Box<Ordered<i32>> = List::ordered();
+
+    assert_eq!(ListFormat::new().display(l.as_ref()).to_string(), "[]");
+    assert_eq!(
+        ListFormat::new().arrow().display(l.as_ref()).to_string(),
+        "nil"
+    );
+}
+
+#[test]
+fn test_format_max() {
+    let l = Fifo::from(vec![1, 2, 3, 4, 5]);
+
+    let two = ListFormat::new().max(2);
+    assert_eq!(two.display(&l).to_string(), "[1, 2, … 3 more]");
+    assert_eq!(two.arrow().display(&l).to_string(), "1 -> 2 -> … 3 more");
+
+    let none = ListFormat::new().max(0);
+    assert_eq!(none.display(&l).to_string(), "[… 5 more]");
+    assert_eq!(none.arrow().display(&l).to_string(), "… 5 more");
+
+    // nothing to cut
+    let all = ListFormat::new().max(5);
+    assert_eq!(all.display(&l).to_string(), "[1, 2, 3, 4, 5]");
+}
+
+#[test]
+fn test_format_kind_labels() {
+    let lists: Vec<(Box<dyn Methods<i32>>, &str)> = vec![
+        (List::lifo(), "Lifo"),
+        (List::fifo(), "Fifo"),
+        (List::ordered(), "Ordered"),
+        (List::priority(), "Priority"),
+        (List::deque(), "Deque"),
+        (List::sync_fifo(), "SyncFifo"),
+        (Box::new(List::waiting(List::sync_lifo())), "SyncLifo"),
+    ];
+
+    for (l, label) in lists {
+        l.push(1);
+        assert_eq!(l.to_string(), format!("{} [1]", label));
+    }
+}
+
+#[test]
+fn test_display_dyn_methods() {
+    let l: Box<dyn Methods<i32>> = Box::new(Ordered::from(vec![3, 1, 2]));
+    assert_eq!(format!("{}", l), "Ordered [1, 2, 3]");
+}
+
+#[test]
+fn test_debug_variants() {
+    assert_eq!(format!("{:?}", Lifo::from(vec![1, 2])), "Lifo([2, 1])");
+    assert_eq!(format!("{:?}", Fifo::from(vec!["a"])), "Fifo([\"a\"])");
+    assert_eq!(
+        format!("{:?}", Ordered::from(vec![2, 1])),
+        "Ordered([1, 2])"
+    );
+
+    let p = List::priority();
+    p.push(2);
+    p.push(1);
+    assert_eq!(format!("{:?}", p), "Priority([1, 2])");
+
+    let d = List::deque();
+    d.push(1);
+    d.push_front(0);
+    assert_eq!(format!("{:?}", d), "Deque([0, 1])");
+
+    let s = List::sync_ordered();
+    s.push(2);
+    s.push(1);
+    assert_eq!(format!("{:?}", s), "SyncOrdered([1, 2])");
+
+    let a = List::waiting(List::sync_fifo());
+    a.push(1);
+    assert_eq!(format!("{:?}", a), "AsyncList([1])");
+
+    assert_eq!(
+        format!("{:#?}", Fifo::from(vec![1])),
+        "Fifo(\n    [\n        1,\n    ],\n)"
+    );
+}
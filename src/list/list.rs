@@ -1,4 +1,5 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
 use std::fmt::Display;
 use std::rc::Rc;
 
@@ -6,79 +7,330 @@ use super::Node;
 
 pub trait Methods<T>
 where
-    T: Clone + Copy + Default,
+    T: Clone,
 {
     fn head(&self) -> Rc<Node<T>>;
-    fn size(&self) -> u8;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    // Lists with `Overflow::Reject` have to be pushed to with
+    // `try_push`, a `push` has no way to report a full list and
+    // losing the value is a bug, caught by a debug assertion.
     fn push(&self, val: T);
+    // like `push` but hands the value back when a bounded
+    // list with `Overflow::Reject` is full.
+    fn try_push(&self, val: T) -> Result<(), Full<T>> {
+        self.push(val);
+        Ok(())
+    }
+    // maximum number of values, `None` when unbounded.
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+    fn remaining(&self) -> Option<usize> {
+        self.capacity().map(|cap| cap.saturating_sub(self.len()))
+    }
     fn pop(&self) -> Option<T>;
-    fn remove(&self, val: T) -> bool;
-    fn contains(&self, val: T) -> bool;
+    // value the next `pop` would return, left in the list.
+    fn peek(&self) -> Option<T> {
+        self.head().get_value().cloned()
+    }
+    // value at the other end of the list from `peek`.
+    fn peek_last(&self) -> Option<T> {
+        self.iter().last()
+    }
+    fn remove(&self, val: &T) -> bool;
+    // removes every value equal to `val`, returns how many were removed.
+    fn remove_all(&self, val: &T) -> usize {
+        let mut removed = 0;
+        while self.remove(val) {
+            removed += 1;
+        }
+        removed
+    }
+    // removes and returns the first value, in `pop` order, matching `pred`.
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T>;
+    // keeps only the values for which `keep` returns true.
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool);
+    // lazily removes the values matching `pred`, values the iterator
+    // doesn't get to stay in the list. Lists built on `List` walk the
+    // chain once, by default each step looks for the next match from
+    // the head again so `pred` can see a value it kept more than once.
+    fn drain_filter<'a, F>(&'a self, mut pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        Self: Sized,
+        T: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        std::iter::from_fn(move || self.remove_first_where(&mut pred))
+    }
+    fn contains(&self, val: &T) -> bool;
+    fn iter(&self) -> ListIterator<T> {
+        ListIterator::new(self.head())
+    }
+    fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
     fn impl_name(&self) -> &'static str {
         std::any::type_name::<Self>()
     }
 }
 
-#[derive(Default)]
-pub struct List<T: Clone + Copy> {
+// What a bounded list does with a push once it is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    // refuse the new value, `try_push` returns it back. Only
+    // push to these with `try_push`.
+    Reject,
+    // make room by dropping the oldest value, the head of a
+    // `Fifo` or the bottom of a `Lifo`.
+    DropOldest,
+    // silently drop the new value.
+    DropNewest,
+}
+
+// Error returned by `try_push` on a full list, carries the value back.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Full<T>(pub T);
+
+impl<T> Display for Full<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        fmt.write_str("list is full")
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for Full<T> {}
+
+// Error returned by `insert_at`, carries the value back.
+#[derive(Debug, PartialEq, Eq)]
+pub enum InsertError<T> {
+    // index past the end of the list.
+    OutOfRange(T),
+    // bounded list with no room left.
+    Full(T),
+}
+
+impl<T> InsertError<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            InsertError::OutOfRange(val) | InsertError::Full(val) => val,
+        }
+    }
+}
+
+impl<T> Display for InsertError<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            InsertError::OutOfRange(_) => fmt.write_str("index out of range"),
+            InsertError::Full(_) => fmt.write_str("list is full"),
+        }
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for InsertError<T> {}
+
+pub struct List<T> {
     first: RefCell<Rc<Node<T>>>,
+    // last node in the chain, nil when the list is empty.
+    last: RefCell<Rc<Node<T>>>,
+    len: Cell<usize>,
+    // values removed so far, tells a `DrainFilter` whether the node
+    // it stopped at may have been unlinked meanwhile.
+    removals: Cell<usize>,
+    bound: Option<(usize, Overflow)>,
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List {
+            first: RefCell::new(Node::nil()),
+            last: RefCell::new(Node::nil()),
+            len: Cell::new(0),
+            removals: Cell::new(0),
+            bound: None,
+        }
+    }
+}
+
+impl<T> List<T> {
+    pub fn iter(&self) -> ListIterator<T> {
+        ListIterator::new(self.first.borrow().clone())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn capacity(&self) -> Option<usize> {
+        self.bound.map(|(cap, _)| cap)
+    }
+
+    // policy to apply on the next push, `None` while there is room.
+    fn overflow(&self) -> Option<Overflow> {
+        match self.bound {
+            Some((cap, policy)) if self.len() >= cap => Some(policy),
+            _ => None,
+        }
+    }
+
+    // every push, pop and remove goes through here
+    // so `len` never has to walk the chain.
+    fn grow(&self) {
+        self.len.set(self.len.get() + 1);
+    }
+
+    fn shrink(&self) {
+        self.set_len(self.len() - 1);
+    }
+
+    fn set_len(&self, len: usize) {
+        if len < self.len() {
+            self.removals.set(self.removals.get() + 1);
+        };
+        self.len.set(len);
+    }
+
+    fn push_front(&self, new: Rc<Node<T>>) {
+        if self.is_empty() {
+            self.last.replace(new.clone());
+        } else {
+            new.set_next(self.first.borrow().clone());
+        };
+        self.first.replace(new);
+        self.grow();
+    }
+
+    fn push_back(&self, new: Rc<Node<T>>) {
+        if self.is_empty() {
+            self.first.replace(new.clone());
+        } else {
+            self.last.borrow().set_next(new.clone());
+        };
+        self.last.replace(new);
+        self.grow();
+    }
+
+    // links `new` right after `prev`, which must be part of this list.
+    fn insert_after(&self, prev: &Rc<Node<T>>, new: Rc<Node<T>>) {
+        if Rc::ptr_eq(prev, &self.last.borrow()) {
+            return self.push_back(new);
+        };
+        if let Some(next) = prev.next() {
+            new.set_next(next.borrow().clone());
+        };
+        prev.set_next(new);
+        self.grow();
+    }
 }
 
 impl<T> List<T>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + PartialOrd,
 {
-    pub fn lifo() -> Box<Lifo<T>> {
-        Box::new(Lifo { l: List::default() })
+    pub fn ordered() -> Box<Ordered<T>> {
+        Box::new(Ordered {
+            l: List::default(),
+            cmp: Compare::Fn(partial_cmp),
+        })
     }
 
-    pub fn fifo() -> Box<Fifo<T>> {
-        Box::new(Fifo { l: List::default() })
+    // largest values first
+    pub fn ordered_desc() -> Box<Ordered<T>> {
+        Box::new(Ordered {
+            l: List::default(),
+            cmp: Compare::Fn(|a, b| partial_cmp(b, a)),
+        })
     }
+}
 
-    pub fn ordered() -> Box<Ordered<T>> {
-        Box::new(Ordered { l: List::default() })
+impl<T> List<T>
+where
+    T: Clone,
+{
+    pub fn ordered_by<F>(cmp: F) -> Box<Ordered<T>>
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        Box::new(Ordered {
+            l: List::default(),
+            cmp: Compare::Closure(Rc::new(cmp)),
+        })
     }
 
-    pub fn size(&self) -> u8 {
-        let mut count = 0;
-        let mut node = self.head();
-        loop {
-            if node.is_nil() {
-                return count;
-            };
-            count += 1;
-            node = match node.next() {
-                Some(next) => next.borrow().clone(),
-                None => Node::nil(),
-            };
-        }
+    pub fn ordered_by_key<K, F>(key: F) -> Box<Ordered<T>>
+    where
+        K: PartialOrd,
+        F: Fn(&T) -> K + 'static,
+    {
+        Self::ordered_by(move |a, b| partial_cmp(&key(a), &key(b)))
     }
 
     pub fn head(&self) -> Rc<Node<T>> {
         self.first.borrow().clone()
     }
 
+    pub fn peek(&self) -> Option<T> {
+        self.first.borrow().get_value().cloned()
+    }
+
+    pub fn peek_last(&self) -> Option<T> {
+        self.last.borrow().get_value().cloned()
+    }
+
     pub fn pop(&self) -> Option<T> {
         // pop from the beggining
-        self.first
-            .replace(match self.head().next() {
-                Some(next) => next.borrow().clone(),
-                None => Node::nil(),
-            })
-            .get_value()
+        if self.is_empty() {
+            return None;
+        };
+
+        let next = match self.head().next() {
+            Some(next) => next.borrow().clone(),
+            None => {
+                // popping the only node, drop the tail reference
+                // so the value can be moved out.
+                self.last.replace(Node::nil());
+                Node::nil()
+            }
+        };
+        self.shrink();
+        Node::take_value(self.first.replace(next))
     }
 
-    pub fn contains(&self, val: T) -> bool {
-        let target = Node::new(val);
+    // pops from the end, walks the chain to find the new last node.
+    pub fn pop_back(&self) -> Option<T> {
+        if self.len() <= 1 {
+            return self.pop();
+        };
+
         let mut node = self.head();
         loop {
-            if node.is_nil() {
-                return false;
+            let next = node.next().unwrap().borrow().clone();
+            if next.next().is_none() {
+                break;
             };
+            node = next;
+        }
+
+        node.set_next(Node::nil());
+        self.shrink();
+        Node::take_value(self.last.replace(node))
+    }
 
-            if node == target {
-                return true;
+    pub fn contains_where<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut node = self.head();
+        loop {
+            match node.get_value() {
+                None => return false,
+                Some(val) if pred(val) => return true,
+                Some(_) => (),
             };
 
             let next = node.next();
@@ -91,55 +343,691 @@ where
         }
     }
 
-    pub fn remove(&self, val: T) -> bool {
-        let target = Node::new(val);
+    // unlinks the first node matching `pred`
+    pub fn remove_where<F>(&self, pred: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.remove_first_where(pred).is_some()
+    }
+
+    // unlinks the first node matching `pred` and returns its value.
+    pub fn remove_first_where<F>(&self, mut pred: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
         let mut node = self.head();
 
-        if node.is_nil() {
-            return false;
+        if node.get_value().is_some_and(&mut pred) {
+            drop(node);
+            return self.pop();
         };
-        if node == target {
-            self.first.replace(match node.next() {
-                None => Node::nil(),
+
+        loop {
+            let prev = node;
+            node = prev.next()?.borrow().clone();
+
+            if node.get_value().is_some_and(&mut pred) {
+                self.unlink_after(&prev, &node);
+                return Node::take_value(node);
+            };
+        }
+    }
+
+    // unlinks every node for which `keep` returns false, in one pass.
+    pub fn retain<F>(&self, mut keep: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        while self.head().get_value().is_some_and(|v| !keep(v)) {
+            self.pop();
+        }
+
+        let mut prev = self.head();
+        loop {
+            let node = match prev.next() {
                 Some(next) => next.borrow().clone(),
-            });
-            return true;
+                None => return,
+            };
+
+            if node.get_value().is_some_and(&mut keep) {
+                prev = node;
+                continue;
+            };
+            self.unlink_after(&prev, &node);
+        }
+    }
+
+    // unlinks `node`, which comes right after `prev`.
+    fn unlink_after(&self, prev: &Rc<Node<T>>, node: &Rc<Node<T>>) {
+        let hop = match node.next() {
+            None => {
+                self.last.replace(prev.clone());
+                Node::nil()
+            }
+            Some(next_ref) => next_ref.borrow().clone(),
+        };
+        prev.set_next(hop);
+        self.shrink();
+    }
+
+    // lazy `retain`, hands out the values matching `pred` as it goes.
+    pub fn drain_filter<F>(&self, pred: F) -> DrainFilter<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        DrainFilter {
+            list: self,
+            prev: None,
+            removals: self.removals.get(),
+            pred,
+        }
+    }
+}
+
+// Positional access, index 0 is the head of the list,
+// the value `pop` would return.
+impl<T> List<T>
+where
+    T: Clone,
+{
+    fn node_at(&self, idx: usize) -> Option<Rc<Node<T>>> {
+        if idx >= self.len() {
+            return None;
         };
 
+        let mut node = self.head();
+        for _ in 0..idx {
+            let next = node.next()?.borrow().clone();
+            node = next;
+        }
+        Some(node)
+    }
+
+    pub fn get(&self, idx: usize) -> Option<T> {
+        self.node_at(idx)?.get_value().cloned()
+    }
+
+    pub fn insert_at(&self, idx: usize, val: T) -> Result<(), InsertError<T>> {
+        if idx > self.len() {
+            return Err(InsertError::OutOfRange(val));
+        };
+        if self.overflow().is_some() {
+            return Err(InsertError::Full(val));
+        };
+
+        match idx {
+            0 => self.push_front(Node::new(val)),
+            _ => self.insert_after(&self.node_at(idx - 1).unwrap(), Node::new(val)),
+        };
+        Ok(())
+    }
+
+    pub fn remove_at(&self, idx: usize) -> Option<T> {
+        if idx == 0 {
+            return self.pop();
+        };
+        if idx >= self.len() {
+            return None;
+        };
+
+        let prev = self.node_at(idx - 1)?;
+        let node = prev.next()?.borrow().clone();
+        let hop = match node.next() {
+            Some(next) => next.borrow().clone(),
+            None => {
+                self.last.replace(prev.clone());
+                Node::nil()
+            }
+        };
+        prev.set_next(hop);
+        self.shrink();
+        Node::take_value(node)
+    }
+
+    // moves everything from `idx` onwards into a new list with the
+    // same bound, `None` when `idx` is past the end.
+    pub fn split_off(&self, idx: usize) -> Option<List<T>> {
+        if idx > self.len() {
+            return None;
+        };
+
+        let tail = List {
+            bound: self.bound,
+            ..List::default()
+        };
+        if idx == self.len() {
+            return Some(tail);
+        };
+
+        let (first, last) = match idx {
+            0 => (
+                self.first.replace(Node::nil()),
+                self.last.replace(Node::nil()),
+            ),
+            _ => {
+                let prev = self.node_at(idx - 1).unwrap();
+                let first = prev.next().unwrap().borrow().clone();
+                prev.set_next(Node::nil());
+                (first, self.last.replace(prev))
+            }
+        };
+        tail.first.replace(first);
+        tail.last.replace(last);
+        tail.len.set(self.len() - idx);
+        self.set_len(idx);
+        Some(tail)
+    }
+
+    // moves every value of `other` to the end of this list, `other`
+    // is left empty. Fails without moving anything when a bounded
+    // list doesn't have room for all of them.
+    pub fn append(&self, other: &List<T>) -> Result<(), Full<()>> {
+        // a list can't be linked after itself
+        if std::ptr::eq(self, other) || other.is_empty() {
+            return Ok(());
+        };
+        if self
+            .capacity()
+            .is_some_and(|cap| self.len() + other.len() > cap)
+        {
+            return Err(Full(()));
+        };
+
+        let first = other.first.replace(Node::nil());
+        let last = other.last.replace(Node::nil());
+        if self.is_empty() {
+            self.first.replace(first);
+        } else {
+            self.last.borrow().set_next(first);
+        };
+        self.last.replace(last);
+        self.len.set(self.len() + other.len());
+        other.set_len(0);
+        Ok(())
+    }
+
+    // keeps the first `len` values, does nothing when
+    // the list is already that short.
+    pub fn truncate(&self, len: usize) {
+        if len >= self.len() {
+            return;
+        };
+
+        match len {
+            0 => {
+                self.first.replace(Node::nil());
+                self.last.replace(Node::nil());
+            }
+            _ => {
+                let last = self.node_at(len - 1).unwrap();
+                last.set_next(Node::nil());
+                self.last.replace(last);
+            }
+        };
+        self.set_len(len);
+    }
+}
+
+// Reordering in place, the nodes are relinked rather than
+// having their values copied around.
+impl<T> List<T> {
+    // node linked after `node`, `None` at the end of the chain.
+    fn next_node(node: &Rc<Node<T>>) -> Option<Rc<Node<T>>> {
+        node.next().map(|next| next.borrow().clone())
+    }
+
+    fn first_node(&self) -> Option<Rc<Node<T>>> {
+        match self.is_empty() {
+            true => None,
+            false => Some(self.first.borrow().clone()),
+        }
+    }
+
+    pub fn reverse(&self) {
+        if self.len() < 2 {
+            return;
+        };
+
+        let first = self.first.borrow().clone();
+        let mut prev = Node::nil();
+        let mut node = Some(first.clone());
+        while let Some(cur) = node {
+            node = Self::next_node(&cur);
+            cur.set_next(prev);
+            prev = cur;
+        }
+        self.first.replace(prev);
+        self.last.replace(first);
+    }
+
+    // Stable bottom up merge sort: every pass merges pairs of sorted
+    // runs of `width` nodes into runs twice as long, until a single
+    // run is left. Iterative so long lists don't use up the stack.
+    pub fn sort_by<F>(&self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        if self.len() < 2 {
+            return;
+        };
+
+        let mut head = self.first.borrow().clone();
+        let mut width = 1;
         loop {
-            let prev = node.clone();
+            let mut p = Some(head);
+            let mut first = None;
+            let mut tail: Option<Rc<Node<T>>> = None;
+            let mut merges = 0;
 
-            let nn = node.next();
-            if nn.is_none() {
-                return false;
+            while let Some(start) = p {
+                merges += 1;
+
+                // the second run starts `width` nodes after the first
+                let mut q = Some(start.clone());
+                let mut p_len = 0;
+                while p_len < width && q.is_some() {
+                    q = q.and_then(|node| Self::next_node(&node));
+                    p_len += 1;
+                }
+                let mut q_len = width;
+                p = Some(start);
+
+                while p_len > 0 || (q_len > 0 && q.is_some()) {
+                    // ties are taken from the first run, which keeps the sort stable
+                    let from_p = match (&p, &q) {
+                        _ if p_len == 0 => false,
+                        (Some(a), Some(b)) if q_len > 0 => {
+                            cmp(a.get_value().unwrap(), b.get_value().unwrap()) != Ordering::Greater
+                        }
+                        _ => true,
+                    };
+                    let node = match from_p {
+                        true => {
+                            p_len -= 1;
+                            let node = p.take().unwrap();
+                            p = Self::next_node(&node);
+                            node
+                        }
+                        false => {
+                            q_len -= 1;
+                            let node = q.take().unwrap();
+                            q = Self::next_node(&node);
+                            node
+                        }
+                    };
+
+                    match &tail {
+                        Some(tail) => tail.set_next(node.clone()),
+                        None => first = Some(node.clone()),
+                    };
+                    tail = Some(node);
+                }
+                p = q;
+            }
+
+            let (first, tail) = (first.unwrap(), tail.unwrap());
+            tail.set_next(Node::nil());
+            if merges == 1 {
+                self.first.replace(first);
+                self.last.replace(tail);
+                return;
             };
+            head = first;
+            width *= 2;
+        }
+    }
+
+    // Moves every node of `other` into this list, both sorted by
+    // `cmp`. Values equal to one already here go after it.
+    fn merge_by<F>(&self, other: &List<T>, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // a list can't be merged with itself
+        if std::ptr::eq(self, other) || other.is_empty() {
+            return;
+        };
 
-            let nn = nn.unwrap().borrow().clone();
-            node = nn;
+        let mut a = self.first_node();
+        let mut b = Some(other.first.replace(Node::nil()));
+        let other_last = other.last.replace(Node::nil());
+        self.len.set(self.len() + other.len());
+        other.set_len(0);
 
-            if node.get_value() == target.get_value() {
-                let hop = match node.next() {
-                    None => Node::nil(),
-                    Some(next_ref) => next_ref.borrow().clone(),
-                };
-                prev.set_next(hop);
-                return true;
+        let mut tail: Option<Rc<Node<T>>> = None;
+        while let (Some(x), Some(y)) = (&a, &b) {
+            let from_b = cmp(y.get_value().unwrap(), x.get_value().unwrap()) == Ordering::Less;
+            let node = match from_b {
+                true => b.take().unwrap(),
+                false => a.take().unwrap(),
+            };
+            match from_b {
+                true => b = Self::next_node(&node),
+                false => a = Self::next_node(&node),
             };
+
+            match &tail {
+                Some(tail) => tail.set_next(node.clone()),
+                None => {
+                    self.first.replace(node.clone());
+                }
+            };
+            tail = Some(node);
         }
+
+        // whichever chain is left is linked on as it is
+        let rest = match (a, b) {
+            (Some(a), _) => a,
+            (None, b) => {
+                self.last.replace(other_last);
+                b.unwrap()
+            }
+        };
+        match tail {
+            Some(tail) => tail.set_next(rest),
+            None => {
+                self.first.replace(rest);
+            }
+        };
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + PartialOrd,
+{
+    // values which can't be compared are left where they are
+    // relative to each other, same as in `Ordered`.
+    pub fn sort(&self) {
+        self.sort_by(partial_cmp)
+    }
+
+    // sorts the values once and hands them over to an `Ordered` in
+    // their natural order, much cheaper than pushing them one by one.
+    // `Ordered` lists are never bounded so any bound is dropped.
+    pub fn into_ordered(self) -> Ordered<T> {
+        self.sort();
+        Ordered {
+            l: List {
+                bound: None,
+                ..self
+            },
+            cmp: Compare::Fn(partial_cmp),
+        }
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn lifo() -> Box<Lifo<T>> {
+        Box::new(Lifo { l: List::default() })
+    }
+
+    pub fn fifo() -> Box<Fifo<T>> {
+        Box::new(Fifo { l: List::default() })
+    }
+
+    // with `Overflow::DropOldest` a push on a full stack walks it
+    // to drop the bottom value, so it costs O(cap).
+    pub fn lifo_bounded(cap: usize, policy: Overflow) -> Box<Lifo<T>> {
+        Box::new(Lifo {
+            l: List {
+                bound: Some((cap, policy)),
+                ..List::default()
+            },
+        })
+    }
+
+    pub fn fifo_bounded(cap: usize, policy: Overflow) -> Box<Fifo<T>> {
+        Box::new(Fifo {
+            l: List {
+                bound: Some((cap, policy)),
+                ..List::default()
+            },
+        })
+    }
+
+    pub fn contains(&self, val: &T) -> bool {
+        self.contains_where(|v| v == val)
+    }
+
+    pub fn remove(&self, val: &T) -> bool {
+        self.remove_where(|v| v == val)
+    }
+}
+
+// Ordering used by `Ordered` when none is given, values which
+// can't be compared (eg: NaN) are treated as equal.
+fn partial_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+// last in - first out list
+pub struct Lifo<T> {
+    l: List<T>,
+}
+
+impl<T> Lifo<T> {
+    pub fn iter(&self) -> ListIterator<T> {
+        self.l.iter()
+    }
+}
+
+// positional access, index 0 is the top of the stack.
+impl<T> Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn get(&self, idx: usize) -> Option<T> {
+        self.l.get(idx)
+    }
+
+    pub fn insert_at(&self, idx: usize, val: T) -> Result<(), InsertError<T>> {
+        self.l.insert_at(idx, val)
+    }
+
+    pub fn remove_at(&self, idx: usize) -> Option<T> {
+        self.l.remove_at(idx)
+    }
+
+    pub fn split_off(&self, idx: usize) -> Option<Lifo<T>> {
+        self.l.split_off(idx).map(|l| Lifo { l })
+    }
+
+    // values of `other` go under the ones already here.
+    pub fn append(&self, other: &Lifo<T>) -> Result<(), Full<()>> {
+        self.l.append(&other.l)
+    }
+
+    pub fn truncate(&self, len: usize) {
+        self.l.truncate(len)
+    }
+}
+
+// reordering, sorted lists pop their smallest value first.
+impl<T> Lifo<T> {
+    pub fn reverse(&self) {
+        self.l.reverse()
+    }
+
+    pub fn sort_by<F>(&self, cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.l.sort_by(cmp)
+    }
+}
+
+impl<T> Lifo<T>
+where
+    T: Clone + PartialOrd,
+{
+    pub fn sort(&self) {
+        self.l.sort()
+    }
+
+    pub fn into_ordered(self) -> Ordered<T> {
+        self.l.into_ordered()
+    }
+}
+
+impl<T> Methods<T> for Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.l.len()
+    }
+
+    fn head(&self) -> Rc<Node<T>> {
+        self.l.head()
+    }
+
+    fn pop(&self) -> Option<T> {
+        // pop from the beggining
+        self.l.pop()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.l.contains(val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.l.remove(val)
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        let before = self.len();
+        self.l.retain(|v| v != val);
+        before - self.len()
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn drain_filter<'a, F>(&'a self, pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        self.l.drain_filter(pred)
+    }
+
+    fn push(&self, val: T) {
+        let res = self.try_push(val);
+        debug_assert!(res.is_ok(), "push on a full list, use try_push");
+    }
+
+    fn try_push(&self, val: T) -> Result<(), Full<T>> {
+        match self.l.overflow() {
+            Some(Overflow::Reject) => return Err(Full(val)),
+            Some(Overflow::DropNewest) => return Ok(()),
+            // the oldest value sits at the bottom of the stack
+            Some(Overflow::DropOldest) if self.l.pop_back().is_none() => return Ok(()),
+            _ => (),
+        };
+
+        // append on the beggining
+        self.l.push_front(Node::new(val));
+        Ok(())
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.l.capacity()
+    }
+}
+
+// first in - first out list
+pub struct Fifo<T> {
+    l: List<T>,
+}
+
+impl<T> Fifo<T> {
+    pub fn iter(&self) -> ListIterator<T> {
+        self.l.iter()
+    }
+}
+
+// positional access, index 0 is the next value to be popped.
+impl<T> Fifo<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn get(&self, idx: usize) -> Option<T> {
+        self.l.get(idx)
+    }
+
+    pub fn insert_at(&self, idx: usize, val: T) -> Result<(), InsertError<T>> {
+        self.l.insert_at(idx, val)
+    }
+
+    pub fn remove_at(&self, idx: usize) -> Option<T> {
+        self.l.remove_at(idx)
+    }
+
+    pub fn split_off(&self, idx: usize) -> Option<Fifo<T>> {
+        self.l.split_off(idx).map(|l| Fifo { l })
+    }
+
+    // values of `other` are popped after the ones already here.
+    pub fn append(&self, other: &Fifo<T>) -> Result<(), Full<()>> {
+        self.l.append(&other.l)
+    }
+
+    pub fn truncate(&self, len: usize) {
+        self.l.truncate(len)
+    }
+}
+
+// reordering, sorted lists pop their smallest value first.
+impl<T> Fifo<T> {
+    pub fn reverse(&self) {
+        self.l.reverse()
+    }
+
+    pub fn sort_by<F>(&self, cmp: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.l.sort_by(cmp)
+    }
+}
+
+impl<T> Fifo<T>
+where
+    T: Clone + PartialOrd,
+{
+    pub fn sort(&self) {
+        self.l.sort()
     }
-}
 
-// last in - first out list
-pub struct Lifo<T: Clone + Copy> {
-    l: List<T>,
+    pub fn into_ordered(self) -> Ordered<T> {
+        self.l.into_ordered()
+    }
 }
 
-impl<T> Methods<T> for Lifo<T>
+impl<T> Methods<T> for Fifo<T>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + PartialEq,
 {
-    fn size(&self) -> u8 {
-        self.l.size()
+    fn len(&self) -> usize {
+        self.l.len()
     }
 
     fn head(&self) -> Rc<Node<T>> {
@@ -151,94 +1039,255 @@ where
         self.l.pop()
     }
 
-    fn contains(&self, val: T) -> bool {
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
         self.l.contains(val)
     }
 
-    fn remove(&self, val: T) -> bool {
+    fn remove(&self, val: &T) -> bool {
         self.l.remove(val)
     }
 
+    fn remove_all(&self, val: &T) -> usize {
+        let before = self.len();
+        self.l.retain(|v| v != val);
+        before - self.len()
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn drain_filter<'a, F>(&'a self, pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        self.l.drain_filter(pred)
+    }
+
     fn push(&self, val: T) {
-        // append on the beggining
-        let new = Node::new(val);
-        let head = self.head();
-        if !head.is_nil() {
-            new.set_next(head);
+        let res = self.try_push(val);
+        debug_assert!(res.is_ok(), "push on a full list, use try_push");
+    }
+
+    fn try_push(&self, val: T) -> Result<(), Full<T>> {
+        match self.l.overflow() {
+            Some(Overflow::Reject) => return Err(Full(val)),
+            Some(Overflow::DropNewest) => return Ok(()),
+            Some(Overflow::DropOldest) if self.l.pop().is_none() => return Ok(()),
+            _ => (),
         };
-        self.l.first.replace(new);
+
+        // append in the end
+        self.l.push_back(Node::new(val));
+        Ok(())
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.l.capacity()
     }
 }
 
-// first in - first out list
-pub struct Fifo<T: Clone + Copy> {
+// Boxed closures would force `T: 'static` on every `Ordered`, plain
+// `fn`s don't, so the built in orderings are kept as such.
+// Closures are shared so lists built out of an `Ordered` (eg: by
+// `union`) keep its ordering.
+enum Compare<T> {
+    Fn(fn(&T, &T) -> Ordering),
+    Closure(Rc<CompareFn<T>>),
+}
+
+type CompareFn<T> = dyn Fn(&T, &T) -> Ordering;
+
+impl<T> Clone for Compare<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Compare::Fn(cmp) => Compare::Fn(*cmp),
+            Compare::Closure(cmp) => Compare::Closure(cmp.clone()),
+        }
+    }
+}
+
+impl<T> Compare<T> {
+    fn call(&self, a: &T, b: &T) -> Ordering {
+        match self {
+            Compare::Fn(cmp) => cmp(a, b),
+            Compare::Closure(cmp) => cmp(a, b),
+        }
+    }
+}
+
+// list kept sorted by `cmp`, smallest values first.
+pub struct Ordered<T> {
     l: List<T>,
+    cmp: Compare<T>,
 }
 
-impl<T> Methods<T> for Fifo<T>
+impl<T> Ordered<T> {
+    pub fn iter(&self) -> ListIterator<T> {
+        self.l.iter()
+    }
+
+    fn is_eq(&self, a: &T, b: &T) -> bool {
+        self.cmp.call(a, b) == Ordering::Equal
+    }
+}
+
+impl<T> Ordered<T>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone,
 {
-    fn size(&self) -> u8 {
-        self.l.size()
+    // smallest value by the list's ordering, the one `pop` returns.
+    pub fn peek_min(&self) -> Option<T> {
+        self.l.peek()
     }
 
-    fn head(&self) -> Rc<Node<T>> {
-        self.l.head()
+    // largest value by the list's ordering.
+    pub fn peek_max(&self) -> Option<T> {
+        self.l.peek_last()
     }
+}
 
-    fn pop(&self) -> Option<T> {
-        // pop from the beggining
-        self.l.pop()
+// Both lists are sorted so each of these walks the two chains once.
+// `other` is expected to be sorted the same way as this list, the
+// results keep this list's ordering. Values are counted, eg: `[1, 1]`
+// and `[1]` only have a single `1` in common.
+impl<T> Ordered<T>
+where
+    T: Clone,
+{
+    // moves every value of `other` into this list, `other` is left
+    // empty. Values equal to one already here go after it.
+    pub fn merge(&self, other: &Ordered<T>) {
+        self.l.merge_by(&other.l, |a, b| self.cmp.call(a, b))
     }
 
-    fn contains(&self, val: T) -> bool {
-        self.l.contains(val)
+    pub fn union(&self, other: &Ordered<T>) -> Ordered<T> {
+        let out = self.empty();
+        self.walk(other, |a, b| {
+            if let Some(val) = a.or(b) {
+                out.l.push_back(Node::new(val));
+            };
+            true
+        });
+        out
     }
 
-    fn remove(&self, val: T) -> bool {
-        self.l.remove(val)
+    pub fn intersection(&self, other: &Ordered<T>) -> Ordered<T> {
+        let out = self.empty();
+        self.walk(other, |a, b| {
+            if let (Some(val), Some(_)) = (a, b) {
+                out.l.push_back(Node::new(val));
+            };
+            true
+        });
+        out
     }
 
-    fn push(&self, val: T) {
-        // append in the end
-        if self.head().is_nil() {
-            self.l.first.replace(Node::new(val));
-            return;
+    // values of this list which are not in `other`.
+    pub fn difference(&self, other: &Ordered<T>) -> Ordered<T> {
+        let out = self.empty();
+        self.walk(other, |a, b| {
+            if let (Some(val), None) = (a, b) {
+                out.l.push_back(Node::new(val));
+            };
+            true
+        });
+        out
+    }
+
+    // true when every value of this list is also in `other`.
+    pub fn is_subset(&self, other: &Ordered<T>) -> bool {
+        let mut subset = true;
+        self.walk(other, |a, b| {
+            subset = !matches!((a, b), (Some(_), None));
+            subset
+        });
+        subset
+    }
+
+    // lazily merges `lists` into a single run of values, sorted by the
+    // ordering of the first list. Equal values come out in the order
+    // of the lists they are in.
+    pub fn merge_all<'a, I>(lists: I) -> MergeIter<T>
+    where
+        I: IntoIterator<Item = &'a Ordered<T>>,
+        T: 'a,
+    {
+        let mut lists = lists.into_iter().peekable();
+        let cmp = match lists.peek() {
+            Some(first) => first.cmp.clone(),
+            // nothing to compare
+            None => Compare::Fn(|_, _| Ordering::Equal),
         };
 
-        let mut n = self.l.first.borrow_mut().clone();
-        loop {
-            let next = n.next();
-            if next.is_none() {
-                n.set_next(Node::new(val));
-                break;
-            };
-            let nn = next.unwrap().borrow().clone();
-            n = nn;
+        let mut heap: Vec<_> = lists
+            .enumerate()
+            .filter_map(|(idx, list)| {
+                let mut values = list.iter();
+                values.next().map(|val| (val, idx, values))
+            })
+            .collect();
+        for idx in (0..heap.len() / 2).rev() {
+            MergeIter::sift_down(&cmp, &mut heap, idx);
         }
+        MergeIter { cmp, heap }
     }
-}
 
-impl<T> Display for dyn Methods<T>
-where
-    T: Clone + Copy + Default + Display + 'static,
-{
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
-        fmt.write_fmt(format_args!("{} {}", self.impl_name(), self.head()))
+    // empty list with the same ordering.
+    fn empty(&self) -> Ordered<T> {
+        Ordered {
+            l: List::default(),
+            cmp: self.cmp.clone(),
+        }
     }
-}
 
-pub struct Ordered<T: Clone + Copy> {
-    l: List<T>,
+    // Calls `f` for every value in order, with `(Some, None)` for values
+    // only in this list, `(None, Some)` for values only in `other` and
+    // `(Some, Some)` for a pair of equal values. Stops once `f` returns
+    // false.
+    fn walk<F>(&self, other: &Ordered<T>, mut f: F)
+    where
+        F: FnMut(Option<T>, Option<T>) -> bool,
+    {
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        loop {
+            let order = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => self.cmp.call(x, y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return,
+            };
+            let more = match order {
+                Ordering::Less => f(a.next(), None),
+                Ordering::Greater => f(None, b.next()),
+                Ordering::Equal => f(a.next(), b.next()),
+            };
+            if !more {
+                return;
+            };
+        }
+    }
 }
 
 impl<T> Methods<T> for Ordered<T>
 where
-    T: Clone + Copy + Default + PartialOrd,
+    T: Clone,
 {
-    fn size(&self) -> u8 {
-        self.l.size()
+    fn len(&self) -> usize {
+        self.l.len()
     }
 
     fn head(&self) -> Rc<Node<T>> {
@@ -249,24 +1298,59 @@ where
         self.l.pop()
     }
 
-    fn contains(&self, val: T) -> bool {
-        self.l.contains(val)
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
     }
 
-    fn remove(&self, val: T) -> bool {
-        self.l.remove(val)
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.l.contains_where(|v| self.is_eq(v, val))
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.l.remove_where(|v| self.is_eq(v, val))
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        let before = self.len();
+        self.l.retain(|v| !self.is_eq(v, val));
+        before - self.len()
+    }
+
+    // removing values never breaks the ordering.
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn drain_filter<'a, F>(&'a self, pred: F) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+        F: FnMut(&T) -> bool + 'a,
+    {
+        self.l.drain_filter(pred)
     }
 
     fn push(&self, val: T) {
-        let new = Node::new(val);
+        // new values go in front of the ones they compare equal to.
+        let goes_before = |node: &Rc<Node<T>>| match node.get_value() {
+            Some(v) => self.cmp.call(&val, v) != Ordering::Greater,
+            None => true,
+        };
+
         let mut node = self.head();
 
-        if node.is_nil() {
-            self.l.first.replace(new);
+        if goes_before(&node) {
+            self.l.push_front(Node::new(val));
             return;
-        } else if new <= node {
-            new.set_next(self.head());
-            self.l.first.replace(new);
+        } else if !goes_before(&self.l.last.borrow()) {
+            self.l.push_back(Node::new(val));
             return;
         };
 
@@ -274,15 +1358,14 @@ where
             match node.next() {
                 Some(next_ref) => {
                     let next = next_ref.borrow().clone();
-                    if node < new && new <= next {
-                        new.set_next(next);
-                        node.set_next(new);
+                    if goes_before(&next) {
+                        self.l.insert_after(&node, Node::new(val));
                         return;
                     };
                     node = next;
                 }
                 None => {
-                    node.set_next(new);
+                    self.l.push_back(Node::new(val));
                     return;
                 }
             }
@@ -290,14 +1373,13 @@ where
     }
 }
 
-pub struct ListIterator<T: Clone + Copy> {
+// Walks a node chain without consuming the list, values are cloned
+// since the list may still be changed through `&self` meanwhile.
+pub struct ListIterator<T> {
     current: Rc<Node<T>>,
 }
 
-impl<T> ListIterator<T>
-where
-    T: Clone + Copy,
-{
+impl<T> ListIterator<T> {
     pub fn new(current: Rc<Node<T>>) -> Self {
         ListIterator { current }
     }
@@ -305,30 +1387,332 @@ where
 
 impl<T> Iterator for ListIterator<T>
 where
-    T: Clone + Copy + Default + std::cmp::PartialEq,
+    T: Clone,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let val = self.current.get_value();
-
-        self.current = match self.current.next() {
+        let next = match self.current.next() {
             Some(next) => next.borrow().clone(),
             None => Node::nil(),
         };
 
-        val
+        // values are moved out of nodes nobody else holds.
+        Node::take_value(std::mem::replace(&mut self.current, next))
+    }
+}
+
+// Iterator returned by `drain_filter` on the lists built on `List`.
+// It carries on from the last node it kept, so every value is only
+// looked at once, unless the list lost values some other way since
+// the last step. Then that node may be gone and it starts over from
+// the head.
+pub struct DrainFilter<'a, T, F> {
+    list: &'a List<T>,
+    // last node kept, `None` while at the head.
+    prev: Option<Rc<Node<T>>>,
+    removals: usize,
+    pred: F,
+}
+
+impl<T, F> Iterator for DrainFilter<'_, T, F>
+where
+    T: Clone,
+    F: FnMut(&T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.list.removals.get() != self.removals {
+            self.prev = None;
+        };
+
+        let mut node = match &self.prev {
+            Some(prev) => List::next_node(prev),
+            None => self.list.first_node(),
+        };
+        while let Some(cur) = node {
+            if cur.get_value().is_some_and(&mut self.pred) {
+                let val = match &self.prev {
+                    Some(prev) => {
+                        self.list.unlink_after(prev, &cur);
+                        Node::take_value(cur)
+                    }
+                    None => {
+                        drop(cur);
+                        self.list.pop()
+                    }
+                };
+                self.removals = self.list.removals.get();
+                return val;
+            };
+            node = List::next_node(&cur);
+            self.prev = Some(cur);
+        }
+        None
+    }
+}
+
+// Iterator returned by `Ordered::merge_all`, keeps the next value of
+// each list in a min-heap so every step is O(log k) for k lists.
+pub struct MergeIter<T> {
+    cmp: Compare<T>,
+    heap: Vec<Run<T>>,
+}
+
+// next value, index of the list it came from and the rest of it.
+type Run<T> = (T, usize, ListIterator<T>);
+
+impl<T> MergeIter<T> {
+    // ties go to the earlier list so the merge is stable.
+    fn less(cmp: &Compare<T>, a: &Run<T>, b: &Run<T>) -> bool {
+        match cmp.call(&a.0, &b.0) {
+            Ordering::Equal => a.1 < b.1,
+            order => order == Ordering::Less,
+        }
+    }
+
+    fn sift_down(cmp: &Compare<T>, heap: &mut [Run<T>], mut idx: usize) {
+        loop {
+            let (left, right) = (2 * idx + 1, 2 * idx + 2);
+            let mut smallest = idx;
+
+            if left < heap.len() && Self::less(cmp, &heap[left], &heap[smallest]) {
+                smallest = left;
+            };
+            if right < heap.len() && Self::less(cmp, &heap[right], &heap[smallest]) {
+                smallest = right;
+            };
+            if smallest == idx {
+                return;
+            };
+            heap.swap(idx, smallest);
+            idx = smallest;
+        }
+    }
+}
+
+impl<T> Iterator for MergeIter<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let top = self.heap.first_mut()?;
+        let val = match top.2.next() {
+            Some(next) => std::mem::replace(&mut top.0, next),
+            None => self.heap.swap_remove(0).0,
+        };
+        Self::sift_down(&self.cmp, &mut self.heap, 0);
+        Some(val)
+    }
+}
+
+// Consuming iterator, pops every value off the list it owns.
+pub struct IntoIter<T> {
+    l: List<T>,
+}
+
+impl<T> Iterator for IntoIter<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.l.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.l.len(), Some(self.l.len()))
     }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> where T: Clone {}
+
 impl<T> IntoIterator for Lifo<T>
 where
-    T: Clone + Copy + Default + std::cmp::PartialEq,
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { l: self.l }
+    }
+}
+
+impl<T> IntoIterator for Fifo<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { l: self.l }
+    }
+}
+
+impl<T> IntoIterator for Ordered<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { l: self.l }
+    }
+}
+
+impl<T> IntoIterator for &Lifo<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = ListIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for &Fifo<T>
+where
+    T: Clone,
+{
+    type Item = T;
+    type IntoIter = ListIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for &Ordered<T>
+where
+    T: Clone,
 {
     type Item = T;
     type IntoIter = ListIterator<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        ListIterator::new(self.l.first.borrow().clone())
+        self.iter()
+    }
+}
+
+impl<T> FromIterator<T> for Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let l = Lifo { l: List::default() };
+        iter.into_iter().for_each(|val| l.push(val));
+        l
+    }
+}
+
+impl<T> FromIterator<T> for Fifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let l = Fifo { l: List::default() };
+        iter.into_iter().for_each(|val| l.push(val));
+        l
+    }
+}
+
+impl<T> FromIterator<T> for Ordered<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let l = *List::ordered();
+        iter.into_iter().for_each(|val| l.push(val));
+        l
+    }
+}
+
+impl<T> Extend<T> for Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+impl<T> Extend<T> for Fifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+impl<T> Extend<T> for Ordered<T>
+where
+    T: Clone,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+impl<T> From<Vec<T>> for Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for Fifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for Ordered<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Lifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Fifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Ordered<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
     }
 }
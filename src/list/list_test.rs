@@ -1,5 +1,6 @@
 use super::list::List;
 use super::list::Methods;
+use super::list::{Fifo, Full, InsertError, Lifo, Ordered, Overflow};
 
 #[test]
 fn test_lifo_push_remove_and_contains() {
@@ -10,11 +11,11 @@ fn test_lifo_push_remove_and_contains() {
     l.push(4);
     l.push(2);
 
-    assert!(l.contains(3));
-    assert!(l.remove(3));
+    assert!(l.contains(&3));
+    assert!(l.remove(&3));
 
-    assert!(!l.contains(3));
-    assert!(!l.remove(3));
+    assert!(!l.contains(&3));
+    assert!(!l.remove(&3));
 }
 
 #[test]
@@ -25,31 +26,31 @@ fn test_lifo_push_and_contains() {
     l.push(3);
     l.push(2);
 
-    assert!(l.contains(3));
-    assert!(l.contains(1));
-    assert!(l.contains(2));
-    assert!(!l.contains(10));
+    assert!(l.contains(&3));
+    assert!(l.contains(&1));
+    assert!(l.contains(&2));
+    assert!(!l.contains(&10));
 }
 
 #[test]
-fn test_lifo_push_and_size() {
+fn test_lifo_push_and_len() {
     let l = List::lifo();
 
     l.push(1.0);
     l.push(3.0);
     l.push(2.0);
 
-    assert_eq!(l.size(), 3);
+    assert_eq!(l.len(), 3);
 
     l.pop();
-    assert_eq!(l.size(), 2);
+    assert_eq!(l.len(), 2);
     l.pop();
     l.pop();
-    assert_eq!(l.size(), 0);
+    assert_eq!(l.len(), 0);
 
     // pop once more to check for possible errors
     l.pop();
-    assert_eq!(l.size(), 0);
+    assert_eq!(l.len(), 0);
 }
 
 #[test]
@@ -72,7 +73,7 @@ fn test_list_push_and_iterate_float32() {
     l.push(3.0);
     l.push(2.0);
 
-    assert_eq!(l.size(), 3);
+    assert_eq!(l.len(), 3);
 
     let mut iter = l.into_iter();
     assert_eq!(iter.next(), Some(2.0));
@@ -89,7 +90,7 @@ fn test_list_push_and_iterate_u8() {
     l.push(3);
     l.push(2);
 
-    assert_eq!(l.size(), 3);
+    assert_eq!(l.len(), 3);
 
     let mut iter = l.into_iter();
     assert_eq!(iter.next(), Some(2));
@@ -127,3 +128,1223 @@ fn test_ordered_push_and_pop() {
     assert_eq!(l.pop(), Some(4));
     assert_eq!(l.pop(), None);
 }
+
+#[test]
+fn test_fifo_owned_values() {
+    let l = List::fifo();
+
+    l.push(vec![1u8, 2]);
+    l.push(vec![3u8]);
+
+    assert!(l.contains(&vec![3u8]));
+    assert_eq!(l.pop(), Some(vec![1u8, 2]));
+    assert_eq!(l.pop(), Some(vec![3u8]));
+    assert_eq!(l.pop(), None);
+}
+
+#[test]
+fn test_ordered_owned_values() {
+    let l = List::ordered();
+
+    l.push("pear".to_string());
+    l.push("apple".to_string());
+    l.push("fig".to_string());
+
+    assert!(l.remove(&"fig".to_string()));
+    assert_eq!(l.pop().as_deref(), Some("apple"));
+    assert_eq!(l.pop().as_deref(), Some("pear"));
+    assert_eq!(l.pop(), None);
+}
+
+#[test]
+fn test_lifo_pop_while_head_is_held() {
+    let l = List::lifo();
+
+    l.push("a".to_string());
+    l.push("b".to_string());
+
+    // the popped node is still referenced so its value gets cloned
+    let head = l.head();
+    assert_eq!(l.pop().as_deref(), Some("b"));
+    assert_eq!(head.get_value().map(|v| v.as_str()), Some("b"));
+
+    let mut iter = l.into_iter();
+    assert_eq!(iter.next().as_deref(), Some("a"));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_len_past_u8() {
+    let lists: Vec<Box<dyn Methods<u32>>> = vec![List::lifo(), List::fifo(), List::ordered()];
+
+    for l in lists {
+        assert!(l.is_empty());
+        for i in (0..1000).rev() {
+            l.push(i);
+        }
+        assert_eq!(l.len(), 1000);
+        assert!(!l.is_empty());
+
+        assert!(l.remove(&500));
+        assert!(!l.remove(&500));
+        assert_eq!(l.len(), 999);
+
+        l.pop();
+        assert_eq!(l.len(), 998);
+
+        while l.pop().is_some() {}
+        assert_eq!(l.len(), 0);
+        assert!(l.is_empty());
+    }
+}
+
+fn drain<T: Clone>(l: &dyn Methods<T>) -> Vec<T> {
+    let mut out = vec![];
+    while let Some(v) = l.pop() {
+        out.push(v);
+    }
+    out
+}
+
+#[test]
+fn test_fifo_tail_after_pop() {
+    let l = List::fifo();
+
+    l.push(1);
+    l.push(2);
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.pop(), Some(2));
+
+    // the tail must not point at the popped node anymore
+    l.push(3);
+    l.push(4);
+    assert_eq!(drain(l.as_ref()), vec![3, 4]);
+}
+
+#[test]
+fn test_fifo_tail_after_remove_last() {
+    let l = List::fifo();
+
+    l.push(1);
+    l.push(2);
+    l.push(3);
+    assert!(l.remove(&3));
+
+    l.push(4);
+    assert_eq!(drain(l.as_ref()), vec![1, 2, 4]);
+
+    l.push(5);
+    assert!(l.remove(&5));
+    l.push(6);
+    assert_eq!(drain(l.as_ref()), vec![6]);
+}
+
+#[test]
+fn test_fifo_tail_after_draining() {
+    let l = List::fifo();
+
+    for i in 0..3 {
+        l.push(i);
+    }
+    assert_eq!(drain(l.as_ref()), vec![0, 1, 2]);
+    assert!(l.is_empty());
+
+    l.push(7);
+    assert_eq!(l.len(), 1);
+    assert_eq!(drain(l.as_ref()), vec![7]);
+}
+
+#[test]
+fn test_fifo_pop_last_moves_value() {
+    let l = List::fifo();
+    let val = std::rc::Rc::new(());
+
+    l.push(val.clone());
+    assert_eq!(std::rc::Rc::strong_count(&val), 2);
+
+    drop(l.pop());
+    assert_eq!(std::rc::Rc::strong_count(&val), 1);
+}
+
+#[derive(Debug, PartialEq)]
+struct NoClone;
+
+impl Clone for NoClone {
+    fn clone(&self) -> Self {
+        panic!("value was cloned")
+    }
+}
+
+#[test]
+fn test_fifo_remove_first_where_moves_head() {
+    let l = List::fifo();
+    l.push(NoClone);
+
+    assert_eq!(l.remove_first_where(&mut |_| true), Some(NoClone));
+    assert!(l.is_empty());
+}
+
+#[test]
+fn test_fifo_push_many() {
+    let l = List::fifo();
+
+    for i in 0..10_000 {
+        l.push(i);
+    }
+    assert_eq!(l.len(), 10_000);
+    assert_eq!(l.pop(), Some(0));
+}
+
+const MILLION: usize = 1_000_000;
+
+#[test]
+fn test_lifo_drop_million() {
+    let l = List::lifo();
+    for i in 0..MILLION {
+        l.push(i);
+    }
+    assert_eq!(l.len(), MILLION);
+    drop(l);
+}
+
+#[test]
+fn test_fifo_drop_million() {
+    let l = List::fifo();
+    for i in 0..MILLION {
+        l.push(i);
+    }
+    assert_eq!(l.len(), MILLION);
+    drop(l);
+}
+
+#[test]
+fn test_ordered_drop_million() {
+    let l = List::ordered();
+    // descending so every push lands on the head
+    for i in (0..MILLION).rev() {
+        l.push(i);
+    }
+    assert_eq!(l.len(), MILLION);
+    drop(l);
+}
+
+#[test]
+fn test_fifo_drop_million_while_iterating() {
+    let l = List::fifo();
+    for i in 0..MILLION {
+        l.push(i);
+    }
+
+    let mut iter = l.iter();
+    assert_eq!(iter.next(), Some(0));
+    drop(l);
+    // the iterator is now the only owner of the rest
+    assert_eq!(iter.next(), Some(1));
+    drop(iter);
+}
+
+#[test]
+fn test_display_long_list() {
+    let l = List::fifo();
+    for i in 0..MILLION {
+        l.push(i % 10);
+    }
+
+    let s = l.head().to_string();
+    assert!(s.contains("[ 0 -> [ 1 -> [ 2 -> "));
+    assert!(s.contains("[ 8 -> [ 9 -> nil]]]"));
+    assert_eq!(s.matches(']').count(), MILLION);
+}
+
+#[test]
+fn test_ordered_push_after_remove_last() {
+    let l = List::ordered();
+
+    l.push(1);
+    l.push(3);
+    assert!(l.remove(&3));
+
+    l.push(5);
+    l.push(2);
+    assert_eq!(drain(l.as_ref()), vec![1, 2, 5]);
+}
+
+#[test]
+fn test_iter_does_not_consume() {
+    let lifo = List::lifo();
+    let fifo = List::fifo();
+    let ordered = List::ordered();
+
+    for val in [2, 3, 1] {
+        lifo.push(val);
+        fifo.push(val);
+        ordered.push(val);
+    }
+
+    assert_eq!(lifo.iter().collect::<Vec<_>>(), vec![1, 3, 2]);
+    assert_eq!(fifo.iter().collect::<Vec<_>>(), vec![2, 3, 1]);
+    assert_eq!(ordered.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    assert_eq!(lifo.len(), 3);
+    assert_eq!(fifo.len(), 3);
+    assert_eq!(ordered.len(), 3);
+}
+
+#[test]
+fn test_iter_by_reference() {
+    let l = List::fifo();
+    l.push("a".to_string());
+    l.push("b".to_string());
+
+    let mut seen = vec![];
+    for val in l.as_ref() {
+        seen.push(val);
+    }
+    assert_eq!(seen, vec!["a", "b"]);
+    assert_eq!(l.pop().as_deref(), Some("a"));
+}
+
+#[test]
+fn test_iter_on_dyn_methods() {
+    let lists: Vec<Box<dyn Methods<u8>>> = vec![
+        List::lifo(),
+        List::fifo(),
+        List::ordered(),
+        List::sync_lifo(),
+        List::sync_fifo(),
+        List::sync_ordered(),
+    ];
+
+    for l in &lists {
+        l.push(2);
+        l.push(1);
+
+        let mut values = l.iter().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![1, 2]);
+        assert_eq!(l.len(), 2);
+    }
+}
+
+#[test]
+fn test_into_iter_is_exact_size() {
+    let l = List::ordered();
+    l.push(3);
+    l.push(1);
+    l.push(2);
+
+    let mut iter = l.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+
+    let l = List::fifo();
+    l.push(1);
+    l.push(2);
+    assert_eq!(l.into_iter().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_from_iterator_keeps_each_ordering() {
+    let lifo: Lifo<u8> = (1..=3).collect();
+    let fifo: Fifo<u8> = (1..=3).collect();
+    let ordered: Ordered<u8> = [2, 3, 1].into_iter().collect();
+
+    assert_eq!(lifo.to_vec(), vec![3, 2, 1]);
+    assert_eq!(fifo.to_vec(), vec![1, 2, 3]);
+    assert_eq!(ordered.to_vec(), vec![1, 2, 3]);
+    assert_eq!(ordered.len(), 3);
+}
+
+#[test]
+fn test_extend() {
+    let mut lifo = List::lifo();
+    let mut fifo = List::fifo();
+    let mut ordered = List::ordered();
+
+    lifo.push(0);
+    fifo.push(0);
+    ordered.push(4);
+
+    lifo.extend(vec![1, 2]);
+    fifo.extend(vec![1, 2]);
+    ordered.extend(vec![3, 1, 2]);
+
+    assert_eq!(lifo.to_vec(), vec![2, 1, 0]);
+    assert_eq!(fifo.to_vec(), vec![0, 1, 2]);
+    assert_eq!(ordered.to_vec(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_from_vec_and_array() {
+    assert_eq!(Lifo::from(vec![1, 2]).to_vec(), vec![2, 1]);
+    assert_eq!(Fifo::from(vec![1, 2]).to_vec(), vec![1, 2]);
+    assert_eq!(Ordered::from(vec![2, 1]).to_vec(), vec![1, 2]);
+
+    assert_eq!(Lifo::from([1, 2]).to_vec(), vec![2, 1]);
+    assert_eq!(Fifo::from(["a", "b"]).to_vec(), vec!["a", "b"]);
+    assert_eq!(Ordered::from([2.0, 1.5]).to_vec(), vec![1.5, 2.0]);
+
+    assert!(Fifo::<u8>::from([]).to_vec().is_empty());
+}
+
+#[test]
+fn test_ordered_desc() {
+    let l = List::ordered_desc();
+
+    for val in [3, 1, 4, 2] {
+        l.push(val);
+    }
+    assert_eq!(l.to_vec(), vec![4, 3, 2, 1]);
+    assert_eq!(l.pop(), Some(4));
+}
+
+#[test]
+fn test_ordered_by_comparator() {
+    // case insensitive ordering
+    let l = List::ordered_by(|a: &String, b: &String| a.to_lowercase().cmp(&b.to_lowercase()));
+
+    for val in ["banana", "Cherry", "apple"] {
+        l.push(val.to_string());
+    }
+    assert_eq!(l.to_vec(), vec!["apple", "banana", "Cherry"]);
+
+    // contains and remove go through the comparator as well
+    assert!(l.contains(&"CHERRY".to_string()));
+    assert!(l.remove(&"APPLE".to_string()));
+    assert!(!l.contains(&"apple".to_string()));
+    assert_eq!(l.to_vec(), vec!["banana", "Cherry"]);
+}
+
+#[test]
+fn test_ordered_by_into_iter_without_partial_ord() {
+    // neither `PartialEq` nor `PartialOrd`
+    #[derive(Clone)]
+    struct Job(u32);
+
+    let l = List::ordered_by(|a: &Job, b: &Job| b.0.cmp(&a.0));
+    for n in [2, 3, 1] {
+        l.push(Job(n));
+    }
+
+    let iter = (*l).into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.map(|j| j.0).collect::<Vec<_>>(), vec![3, 2, 1]);
+}
+
+#[test]
+fn test_ordered_by_extend_without_partial_ord() {
+    // neither `PartialEq` nor `PartialOrd`
+    #[derive(Clone)]
+    struct Job(u32);
+
+    let mut l = List::ordered_by(|a: &Job, b: &Job| b.0.cmp(&a.0));
+    l.extend([2, 3, 1].map(Job));
+
+    assert_eq!(
+        l.to_vec().iter().map(|j| j.0).collect::<Vec<_>>(),
+        vec![3, 2, 1]
+    );
+}
+
+#[test]
+fn test_ordered_by_key() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Job {
+        priority: u8,
+        name: &'static str,
+    }
+
+    let job = |priority, name| Job { priority, name };
+    let l = List::ordered_by_key(|j: &Job| j.priority);
+
+    l.push(job(2, "b"));
+    l.push(job(1, "a"));
+    l.push(job(3, "c"));
+    // equal keys go in front of the existing ones, like `ordered`
+    l.push(job(2, "b2"));
+
+    let names: Vec<_> = l.iter().map(|j| j.name).collect();
+    assert_eq!(names, vec!["a", "b2", "b", "c"]);
+
+    assert!(l.contains(&job(3, "any")));
+    assert!(l.remove(&job(2, "any")));
+    let names: Vec<_> = l.iter().map(|j| j.name).collect();
+    assert_eq!(names, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_ordered_with_borrowed_values() {
+    let words = vec!["b".to_string(), "a".to_string()];
+    let l = List::ordered();
+
+    for w in &words {
+        l.push(w.as_str());
+    }
+    assert_eq!(l.to_vec(), vec!["a", "b"]);
+}
+
+#[test]
+fn test_unbounded_capacity() {
+    let l = List::fifo();
+    l.push(1);
+
+    assert_eq!(l.capacity(), None);
+    assert_eq!(l.remaining(), None);
+    assert_eq!(l.try_push(2), Ok(()));
+    assert_eq!(l.len(), 2);
+}
+
+#[test]
+fn test_bounded_reject() {
+    let lists: Vec<Box<dyn Methods<u8>>> = vec![
+        List::fifo_bounded(2, Overflow::Reject),
+        List::lifo_bounded(2, Overflow::Reject),
+    ];
+
+    for l in lists {
+        assert_eq!(l.capacity(), Some(2));
+        assert_eq!(l.remaining(), Some(2));
+
+        assert_eq!(l.try_push(1), Ok(()));
+        assert_eq!(l.try_push(2), Ok(()));
+        assert_eq!(l.remaining(), Some(0));
+
+        assert_eq!(l.try_push(3), Err(Full(3)));
+        assert_eq!(l.len(), 2);
+
+        l.pop();
+        assert_eq!(l.remaining(), Some(1));
+        assert_eq!(l.try_push(5), Ok(()));
+        assert!(!l.contains(&3));
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "use try_push")]
+fn test_bounded_reject_push_asserts() {
+    let l = List::fifo_bounded(1, Overflow::Reject);
+    l.push(1);
+    l.push(2);
+}
+
+#[test]
+fn test_bounded_drop_oldest() {
+    let fifo = List::fifo_bounded(3, Overflow::DropOldest);
+    let lifo = List::lifo_bounded(3, Overflow::DropOldest);
+
+    for val in 1..=5 {
+        assert_eq!(fifo.try_push(val), Ok(()));
+        assert_eq!(lifo.try_push(val), Ok(()));
+    }
+
+    assert_eq!(fifo.to_vec(), vec![3, 4, 5]);
+    assert_eq!(lifo.to_vec(), vec![5, 4, 3]);
+    assert_eq!(fifo.len(), 3);
+    assert_eq!(lifo.len(), 3);
+
+    // the tail is still right after dropping from the end of a lifo
+    assert_eq!(drain(lifo.as_ref()), vec![5, 4, 3]);
+    lifo.push(6);
+    assert_eq!(lifo.to_vec(), vec![6]);
+}
+
+#[test]
+fn test_bounded_drop_newest() {
+    let fifo = List::fifo_bounded(2, Overflow::DropNewest);
+    let lifo = List::lifo_bounded(2, Overflow::DropNewest);
+
+    for val in 1..=4 {
+        assert_eq!(fifo.try_push(val), Ok(()));
+        assert_eq!(lifo.try_push(val), Ok(()));
+    }
+
+    assert_eq!(fifo.to_vec(), vec![1, 2]);
+    assert_eq!(lifo.to_vec(), vec![2, 1]);
+}
+
+#[test]
+fn test_bounded_zero_capacity() {
+    for policy in [Overflow::Reject, Overflow::DropOldest, Overflow::DropNewest] {
+        let l = List::fifo_bounded(0, policy);
+        let res = l.try_push(1);
+        assert_eq!(res.is_err(), policy == Overflow::Reject);
+        assert!(l.is_empty());
+        assert_eq!(l.remaining(), Some(0));
+    }
+}
+
+#[test]
+fn test_peek_does_not_pop() {
+    let lifo = Lifo::from(vec![1, 2, 3]);
+    assert_eq!(lifo.peek(), Some(3));
+    assert_eq!(lifo.peek_last(), Some(1));
+    assert_eq!(lifo.len(), 3);
+    assert_eq!(lifo.pop(), Some(3));
+
+    let fifo = Fifo::from(vec![1, 2, 3]);
+    assert_eq!(fifo.peek(), Some(1));
+    assert_eq!(fifo.peek_last(), Some(3));
+    assert_eq!(fifo.pop(), Some(1));
+    assert_eq!(fifo.peek(), Some(2));
+
+    let empty: Box<Fifo<i32>> = List::fifo();
+    assert_eq!(empty.peek(), None);
+    assert_eq!(empty.peek_last(), None);
+}
+
+#[test]
+fn test_peek_last_follows_pops_and_removes() {
+    let l = List::fifo();
+    l.push(1);
+    l.push(2);
+    assert!(l.remove(&2));
+    assert_eq!(l.peek_last(), Some(1));
+
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.peek_last(), None);
+
+    l.push(5);
+    assert_eq!(l.peek(), Some(5));
+    assert_eq!(l.peek_last(), Some(5));
+}
+
+#[test]
+fn test_peek_every_variant() {
+    let lists: Vec<(Box<dyn Methods<i32>>, i32, i32)> = vec![
+        (List::lifo(), 2, 3),
+        (List::fifo(), 3, 2),
+        (List::ordered(), 1, 3),
+        (List::priority(), 1, 3),
+        (List::deque(), 3, 2),
+        (List::deque_lifo(), 2, 3),
+        (List::sync_lifo(), 2, 3),
+        (List::sync_fifo(), 3, 2),
+        (List::sync_ordered(), 1, 3),
+        (Box::new(List::waiting(List::sync_fifo())), 3, 2),
+    ];
+
+    for (l, first, last) in lists {
+        l.push(3);
+        l.push(1);
+        l.push(2);
+
+        assert_eq!(l.peek(), Some(first), "{}", l.impl_name());
+        assert_eq!(l.peek_last(), Some(last), "{}", l.impl_name());
+        assert_eq!(l.len(), 3);
+        assert_eq!(l.pop(), Some(first));
+    }
+}
+
+#[test]
+fn test_ordered_peek_min_and_max() {
+    let l = Ordered::from(vec![5, 1, 3]);
+    assert_eq!(l.peek_min(), Some(1));
+    assert_eq!(l.peek_max(), Some(5));
+
+    l.push(7);
+    l.push(0);
+    assert_eq!(l.peek_min(), Some(0));
+    assert_eq!(l.peek_max(), Some(7));
+
+    // by the list's own ordering
+    let desc = List::ordered_desc();
+    desc.push(1);
+    desc.push(9);
+    assert_eq!(desc.peek_min(), Some(9));
+    assert_eq!(desc.peek_max(), Some(1));
+
+    let empty: Box<Ordered<i32>> = List::ordered();
+    assert_eq!(empty.peek_min(), None);
+    assert_eq!(empty.peek_max(), None);
+}
+
+#[test]
+fn test_get() {
+    let fifo = Fifo::from(vec![1, 2, 3]);
+    assert_eq!(fifo.get(0), Some(1));
+    assert_eq!(fifo.get(2), Some(3));
+    assert_eq!(fifo.get(3), None);
+
+    let lifo = Lifo::from(vec![1, 2, 3]);
+    assert_eq!(lifo.get(0), Some(3));
+    assert_eq!(lifo.get(2), Some(1));
+    assert_eq!(lifo.get(10), None);
+}
+
+#[test]
+fn test_insert_at() {
+    let l = Fifo::from(vec![1, 3]);
+
+    assert_eq!(l.insert_at(1, 2), Ok(()));
+    assert_eq!(l.insert_at(0, 0), Ok(()));
+    assert_eq!(l.insert_at(4, 4), Ok(()));
+    assert_eq!(l.insert_at(6, 9), Err(InsertError::OutOfRange(9)));
+    assert_eq!(l.to_vec(), vec![0, 1, 2, 3, 4]);
+    assert_eq!(l.len(), 5);
+
+    // inserting at the end moves the tail
+    l.push(5);
+    assert_eq!(l.peek_last(), Some(5));
+    assert_eq!(l.to_vec(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_insert_at_bounded() {
+    let l = List::fifo_bounded(2, Overflow::DropOldest);
+    assert_eq!(l.insert_at(0, 1), Ok(()));
+    assert_eq!(l.insert_at(0, 2), Ok(()));
+
+    let err = l.insert_at(1, 3).unwrap_err();
+    assert_eq!(err, InsertError::Full(3));
+    assert_eq!(err.into_inner(), 3);
+    assert_eq!(l.to_vec(), vec![2, 1]);
+}
+
+#[test]
+fn test_remove_at() {
+    let l = Lifo::from(vec![1, 2, 3, 4]);
+
+    assert_eq!(l.remove_at(4), None);
+    assert_eq!(l.remove_at(1), Some(3));
+    assert_eq!(l.remove_at(0), Some(4));
+    assert_eq!(l.to_vec(), vec![2, 1]);
+
+    // removing the last value moves the tail back
+    assert_eq!(l.remove_at(1), Some(1));
+    assert_eq!(l.peek_last(), Some(2));
+    assert_eq!(l.len(), 1);
+
+    assert_eq!(l.remove_at(0), Some(2));
+    assert!(l.is_empty());
+    assert_eq!(l.remove_at(0), None);
+}
+
+#[test]
+fn test_split_off() {
+    let l = Fifo::from(vec![1, 2, 3, 4]);
+
+    assert!(l.split_off(5).is_none());
+
+    let tail = l.split_off(2).unwrap();
+    assert_eq!(l.to_vec(), vec![1, 2]);
+    assert_eq!(tail.to_vec(), vec![3, 4]);
+    assert_eq!((l.len(), tail.len()), (2, 2));
+
+    // both halves keep working as lists
+    l.push(5);
+    tail.push(6);
+    assert_eq!(l.to_vec(), vec![1, 2, 5]);
+    assert_eq!(tail.to_vec(), vec![3, 4, 6]);
+
+    let empty = l.split_off(3).unwrap();
+    assert!(empty.is_empty());
+
+    let all = l.split_off(0).unwrap();
+    assert!(l.is_empty());
+    assert_eq!(all.to_vec(), vec![1, 2, 5]);
+    assert_eq!(all.peek_last(), Some(5));
+}
+
+#[test]
+fn test_split_off_keeps_bound() {
+    let l = List::lifo_bounded(3, Overflow::Reject);
+    l.push(1);
+    l.push(2);
+
+    let tail = l.split_off(1).unwrap();
+    assert_eq!(tail.capacity(), Some(3));
+    assert_eq!(tail.to_vec(), vec![1]);
+}
+
+#[test]
+fn test_append() {
+    let a = Fifo::from(vec![1, 2]);
+    let b = Fifo::from(vec![3, 4]);
+
+    assert_eq!(a.append(&b), Ok(()));
+    assert_eq!(a.to_vec(), vec![1, 2, 3, 4]);
+    assert!(b.is_empty());
+
+    a.push(5);
+    b.push(6);
+    assert_eq!(a.to_vec(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(b.to_vec(), vec![6]);
+
+    // appending to an empty list, and a list to itself
+    let c = Fifo::from(vec![]);
+    assert_eq!(c.append(&a), Ok(()));
+    assert_eq!(c.append(&c), Ok(()));
+    assert_eq!(c.to_vec(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(c.peek_last(), Some(5));
+
+    let lifo = Lifo::from(vec![2, 1]);
+    lifo.append(&Lifo::from(vec![4, 3])).unwrap();
+    assert_eq!(lifo.to_vec(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_append_bounded() {
+    let a = List::fifo_bounded(3, Overflow::DropOldest);
+    a.push(1);
+    a.push(2);
+    let b = Fifo::from(vec![3, 4]);
+
+    assert_eq!(a.append(&b), Err(Full(())));
+    assert_eq!(a.to_vec(), vec![1, 2]);
+    assert_eq!(b.to_vec(), vec![3, 4]);
+
+    b.pop();
+    assert_eq!(a.append(&b), Ok(()));
+    assert_eq!(a.to_vec(), vec![1, 2, 4]);
+}
+
+#[test]
+fn test_truncate() {
+    let l = Fifo::from(vec![1, 2, 3, 4]);
+
+    l.truncate(10);
+    assert_eq!(l.len(), 4);
+
+    l.truncate(2);
+    assert_eq!(l.to_vec(), vec![1, 2]);
+    assert_eq!(l.peek_last(), Some(2));
+    l.push(3);
+    assert_eq!(l.to_vec(), vec![1, 2, 3]);
+
+    l.truncate(0);
+    assert!(l.is_empty());
+    assert_eq!(l.pop(), None);
+    l.push(1);
+    assert_eq!(l.to_vec(), vec![1]);
+}
+
+fn every_variant() -> Vec<Box<dyn Methods<i32>>> {
+    vec![
+        List::lifo(),
+        List::fifo(),
+        List::ordered(),
+        List::priority(),
+        List::deque(),
+        List::sync_lifo(),
+        List::sync_fifo(),
+        List::sync_ordered(),
+        Box::new(List::waiting(List::sync_fifo())),
+    ]
+}
+
+fn sorted(l: &dyn Methods<i32>) -> Vec<i32> {
+    let mut values = l.to_vec();
+    values.sort();
+    values
+}
+
+#[test]
+fn test_retain_every_variant() {
+    for l in every_variant() {
+        for i in [5, 1, 4, 2, 3, 6, 2] {
+            l.push(i);
+        }
+
+        l.retain(&mut |v| v % 2 == 0);
+        assert_eq!(sorted(l.as_ref()), vec![2, 2, 4, 6], "{}", l.impl_name());
+        assert_eq!(l.len(), 4);
+
+        l.retain(&mut |_| false);
+        assert!(l.is_empty());
+        l.push(1);
+        assert_eq!(l.pop(), Some(1));
+    }
+}
+
+#[test]
+fn test_remove_all_every_variant() {
+    for l in every_variant() {
+        for i in [2, 1, 2, 3, 2] {
+            l.push(i);
+        }
+
+        assert_eq!(l.remove_all(&2), 3, "{}", l.impl_name());
+        assert_eq!(l.remove_all(&2), 0);
+        assert_eq!(sorted(l.as_ref()), vec![1, 3]);
+    }
+}
+
+#[test]
+fn test_remove_first_where_every_variant() {
+    for l in every_variant() {
+        for i in [1, 2, 3, 4] {
+            l.push(i);
+        }
+
+        // the even value that would be popped first
+        let first = l.to_vec().into_iter().find(|v| v % 2 == 0);
+        assert_eq!(l.remove_first_where(&mut |v| v % 2 == 0), first);
+        assert_eq!(l.len(), 3, "{}", l.impl_name());
+        assert_eq!(l.remove_first_where(&mut |v| *v > 10), None);
+    }
+}
+
+// `drain_filter` is generic, so it isn't there on `dyn Methods`.
+fn drain_odd_values<L: Methods<i32>>(l: &L) {
+    for i in 1..=6 {
+        l.push(i);
+    }
+
+    let mut drained: Vec<i32> = l.drain_filter(|v| v % 2 == 1).collect();
+    drained.sort();
+    assert_eq!(drained, vec![1, 3, 5], "{}", l.impl_name());
+    assert_eq!(sorted(l), vec![2, 4, 6]);
+}
+
+#[test]
+fn test_drain_filter_every_variant() {
+    drain_odd_values(&*List::lifo());
+    drain_odd_values(&*List::fifo());
+    drain_odd_values(&*List::ordered());
+    drain_odd_values(&*List::priority());
+    drain_odd_values(&*List::deque());
+    drain_odd_values(&*List::sync_lifo());
+    drain_odd_values(&*List::sync_fifo());
+    drain_odd_values(&*List::sync_ordered());
+    drain_odd_values(&List::waiting(List::sync_fifo()));
+}
+
+#[test]
+fn test_drain_filter_checks_each_value_once() {
+    let l = Fifo::from(vec![1, 2, 3, 4, 5, 6]);
+
+    let mut calls = 0;
+    let drained: Vec<i32> = l
+        .drain_filter(|v| {
+            calls += 1;
+            v % 3 == 0
+        })
+        .collect();
+    assert_eq!(drained, vec![3, 6]);
+    assert_eq!(calls, 6);
+
+    // the last value went, the tail moved back
+    assert_eq!(l.peek_last(), Some(5));
+    l.push(7);
+    assert_eq!(l.to_vec(), vec![1, 2, 4, 5, 7]);
+    assert_eq!(l.len(), 5);
+}
+
+#[test]
+fn test_drain_filter_after_other_removals() {
+    let l = Fifo::from(vec![1, 2, 3, 4, 5, 6]);
+
+    let mut drain = l.drain_filter(|v| v % 2 == 0);
+    assert_eq!(drain.next(), Some(2));
+    // the node the iterator stopped at goes away
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(drain.next(), Some(4));
+    assert!(l.remove(&3));
+    assert_eq!(drain.next(), Some(6));
+    assert_eq!(drain.next(), None);
+
+    assert_eq!(l.to_vec(), vec![5]);
+    assert_eq!(l.len(), 1);
+    l.push(7);
+    assert_eq!(l.to_vec(), vec![5, 7]);
+}
+
+#[test]
+fn test_drain_filter_is_lazy() {
+    let l = Fifo::from(vec![1, 2, 3, 4, 5]);
+
+    let mut drain = l.drain_filter(|v| *v > 1);
+    assert_eq!(drain.next(), Some(2));
+    drop(drain);
+
+    // only what was taken is gone
+    assert_eq!(l.to_vec(), vec![1, 3, 4, 5]);
+}
+
+#[test]
+fn test_ordered_stays_sorted_after_removals() {
+    let l = Ordered::from(vec![5, 3, 8, 1, 9, 2, 7]);
+
+    l.retain(&mut |v| *v != 8);
+    assert_eq!(l.remove_first_where(&mut |v| *v > 4), Some(5));
+    assert_eq!(l.drain_filter(|v| *v == 1).count(), 1);
+    assert_eq!(l.to_vec(), vec![2, 3, 7, 9]);
+
+    // the tail is still right, pushes keep going in order
+    l.push(10);
+    l.push(6);
+    assert_eq!(l.to_vec(), vec![2, 3, 6, 7, 9, 10]);
+    assert_eq!(l.peek_max(), Some(10));
+}
+
+#[test]
+fn test_ordered_remove_all_uses_comparator() {
+    let l = List::ordered_by_key(|s: &String| s.to_lowercase());
+    for s in ["a", "B", "b", "c"] {
+        l.push(s.to_string());
+    }
+
+    assert_eq!(l.remove_all(&"b".to_string()), 2);
+    assert_eq!(l.to_vec(), vec!["a".to_string(), "c".to_string()]);
+}
+
+#[test]
+fn test_retain_moves_tail() {
+    let l = Fifo::from(vec![1, 2, 3]);
+
+    l.retain(&mut |v| *v < 3);
+    l.push(4);
+    assert_eq!(l.to_vec(), vec![1, 2, 4]);
+    assert_eq!(l.peek_last(), Some(4));
+}
+
+#[test]
+fn test_reverse() {
+    let l = Fifo::from(vec![1, 2, 3, 4]);
+
+    l.reverse();
+    assert_eq!(l.to_vec(), vec![4, 3, 2, 1]);
+    assert_eq!(l.peek_last(), Some(1));
+    l.push(5);
+    assert_eq!(l.to_vec(), vec![4, 3, 2, 1, 5]);
+
+    // the bottom of the stack ends up on top
+    let l = Lifo::from(vec![1, 2, 3]);
+    l.reverse();
+    assert_eq!(l.to_vec(), vec![1, 2, 3]);
+    assert_eq!(l.pop(), Some(1));
+
+    let l: Fifo<i32> = Fifo::from(vec![]);
+    l.reverse();
+    assert!(l.is_empty());
+}
+
+#[test]
+fn test_sort() {
+    for values in [vec![], vec![1], vec![2, 1], vec![5, 3, 9, 1, 3, 7, 2, 8]] {
+        let l = Fifo::from(values.clone());
+        l.sort();
+
+        let mut expected = values;
+        expected.sort();
+        assert_eq!(l.to_vec(), expected);
+        assert_eq!(l.peek_last(), expected.last().copied());
+        assert_eq!(l.len(), expected.len());
+    }
+
+    let l = Lifo::from(vec![3, 1, 2]);
+    l.sort();
+    assert_eq!(l.pop(), Some(1));
+    l.push(0);
+    assert_eq!(l.to_vec(), vec![0, 2, 3]);
+}
+
+#[test]
+fn test_sort_keeps_tail() {
+    let l = Fifo::from(vec![3, 1, 2]);
+
+    l.sort();
+    l.push(0);
+    assert_eq!(l.to_vec(), vec![1, 2, 3, 0]);
+    assert_eq!(l.peek_last(), Some(0));
+}
+
+#[test]
+fn test_sort_by_is_stable() {
+    let l = Fifo::from(vec![
+        (2, 'a'),
+        (1, 'b'),
+        (2, 'c'),
+        (1, 'd'),
+        (0, 'e'),
+        (2, 'f'),
+    ]);
+
+    l.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        l.to_vec(),
+        vec![(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')]
+    );
+
+    l.sort_by(|a, b| b.0.cmp(&a.0));
+    assert_eq!(
+        l.to_vec(),
+        vec![(2, 'a'), (2, 'c'), (2, 'f'), (1, 'b'), (1, 'd'), (0, 'e')]
+    );
+}
+
+#[test]
+fn test_sort_long_list() {
+    let l: Fifo<u64> = (0..MILLION as u64)
+        .map(|i| (i * 7919) % 1_000_003)
+        .collect();
+
+    l.sort();
+    assert_eq!(l.len(), MILLION);
+    let values = l.to_vec();
+    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_into_ordered() {
+    let l = Fifo::from(vec![5, 1, 4, 2, 3]);
+
+    let o = l.into_ordered();
+    assert_eq!(o.to_vec(), vec![1, 2, 3, 4, 5]);
+    o.push(0);
+    o.push(6);
+    o.push(3);
+    assert_eq!(o.to_vec(), vec![0, 1, 2, 3, 3, 4, 5, 6]);
+    assert_eq!(o.peek_max(), Some(6));
+
+    let o = Lifo::from(vec![2.0, f64::NAN, 1.0]).into_ordered();
+    assert_eq!(o.len(), 3);
+    assert_eq!(o.pop(), Some(1.0));
+
+    // bounds don't carry over
+    let l = List::fifo_bounded(2, Overflow::Reject);
+    l.push(2);
+    l.push(1);
+    let o = l.into_ordered();
+    o.push(3);
+    assert_eq!(o.to_vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_ordered_merge() {
+    let a = Ordered::from(vec![1, 4, 6, 9]);
+    let b = Ordered::from(vec![2, 3, 6, 10, 11]);
+
+    a.merge(&b);
+    assert_eq!(a.to_vec(), vec![1, 2, 3, 4, 6, 6, 9, 10, 11]);
+    assert_eq!(a.len(), 9);
+    assert!(b.is_empty());
+    assert_eq!(b.pop(), None);
+
+    // tail follows the values merged in
+    assert_eq!(a.peek_max(), Some(11));
+    a.push(12);
+    assert_eq!(a.peek_max(), Some(12));
+    b.push(5);
+    assert_eq!(b.to_vec(), vec![5]);
+
+    // merging an empty list or the list itself changes nothing
+    a.merge(&Ordered::from(vec![]));
+    a.merge(&a);
+    assert_eq!(a.len(), 10);
+
+    let empty = Ordered::from(vec![]);
+    empty.merge(&Ordered::from(vec![2, 1]));
+    assert_eq!(empty.to_vec(), vec![1, 2]);
+    assert_eq!(empty.peek_max(), Some(2));
+}
+
+#[test]
+fn test_ordered_merge_keeps_equal_values_in_order() {
+    let a = List::ordered_by_key(|s: &String| s.to_lowercase());
+    let b = List::ordered_by_key(|s: &String| s.to_lowercase());
+    for s in ["a", "B"] {
+        a.push(s.to_string());
+    }
+    for s in ["b", "c"] {
+        b.push(s.to_string());
+    }
+
+    a.merge(&b);
+    assert_eq!(a.to_vec(), vec!["a", "B", "b", "c"]);
+}
+
+#[test]
+fn test_ordered_set_operations() {
+    let a = Ordered::from(vec![1, 2, 2, 3, 5, 8]);
+    let b = Ordered::from(vec![2, 3, 3, 4, 8, 9]);
+
+    assert_eq!(a.union(&b).to_vec(), vec![1, 2, 2, 3, 3, 4, 5, 8, 9]);
+    assert_eq!(a.intersection(&b).to_vec(), vec![2, 3, 8]);
+    assert_eq!(a.difference(&b).to_vec(), vec![1, 2, 5]);
+    assert_eq!(b.difference(&a).to_vec(), vec![3, 4, 9]);
+
+    // neither list is changed
+    assert_eq!(a.len(), 6);
+    assert_eq!(b.len(), 6);
+
+    let empty = Ordered::from(vec![]);
+    assert_eq!(a.union(&empty).to_vec(), a.to_vec());
+    assert!(a.intersection(&empty).is_empty());
+    assert_eq!(empty.difference(&a).len(), 0);
+}
+
+#[test]
+fn test_ordered_is_subset() {
+    let a = Ordered::from(vec![1, 2, 2, 5]);
+
+    assert!(Ordered::from(vec![]).is_subset(&a));
+    assert!(Ordered::from(vec![2, 5]).is_subset(&a));
+    assert!(Ordered::from(vec![1, 2, 2]).is_subset(&a));
+    assert!(a.is_subset(&a));
+    assert!(!Ordered::from(vec![2, 2, 2]).is_subset(&a));
+    assert!(!Ordered::from(vec![0, 1]).is_subset(&a));
+    assert!(!a.is_subset(&Ordered::from(vec![])));
+}
+
+#[test]
+fn test_ordered_set_operations_keep_comparator() {
+    let a = List::ordered_desc();
+    let b = List::ordered_desc();
+    for v in [1, 3, 5] {
+        a.push(v);
+    }
+    for v in [2, 3, 4] {
+        b.push(v);
+    }
+
+    let u = a.union(&b);
+    assert_eq!(u.to_vec(), vec![5, 4, 3, 2, 1]);
+    u.push(6);
+    assert_eq!(u.pop(), Some(6));
+    assert_eq!(a.intersection(&b).to_vec(), vec![3]);
+}
+
+#[test]
+fn test_ordered_merge_all() {
+    let a = Ordered::from(vec![1, 4, 7]);
+    let b = Ordered::from(vec![2, 5, 8, 9]);
+    let c = Ordered::from(vec![]);
+    let d = Ordered::from(vec![0, 3, 6]);
+
+    let merged: Vec<i32> = Ordered::merge_all([&a, &b, &c, &d]).collect();
+    assert_eq!(merged, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(a.len(), 3);
+
+    assert_eq!(Ordered::<i32>::merge_all([]).next(), None);
+    assert_eq!(Ordered::merge_all([&c]).next(), None);
+}
+
+#[test]
+fn test_ordered_merge_all_is_stable() {
+    let lists: Vec<_> = (0..3)
+        .map(|shard| {
+            let l = List::ordered_by_key(|v: &(i32, i32)| v.0);
+            l.push((1, shard));
+            l.push((2, shard));
+            l
+        })
+        .collect();
+
+    let merged: Vec<(i32, i32)> = Ordered::merge_all(lists.iter().map(|l| &**l)).collect();
+    assert_eq!(merged, vec![(1, 0), (1, 1), (1, 2), (2, 0), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn test_ordered_merge_all_is_lazy() {
+    let shards: Vec<Ordered<usize>> = (0..4)
+        .map(|shard| (0..MILLION).filter(|v| v % 4 == shard).collect())
+        .collect();
+
+    let first: Vec<usize> = Ordered::merge_all(&shards).take(5).collect();
+    assert_eq!(first, vec![0, 1, 2, 3, 4]);
+    assert_eq!(Ordered::merge_all(&shards).count(), MILLION);
+}
@@ -1,12 +1,48 @@
+pub mod deque;
+pub mod format;
+#[allow(clippy::module_inception)]
 pub mod list;
 pub mod node;
+pub mod priority;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod snapshot;
+pub mod sync;
+pub mod wait;
 
 // re-export List here
-pub use list::{List, Methods};
+pub use deque::Deque;
+pub use format::{ListFormat, Style};
+pub use list::{Fifo, Full, InsertError, Lifo, List, Methods, Ordered, Overflow};
 pub use node::Node;
+pub use priority::Priority;
+pub use snapshot::{Codec, Kind, SnapshotError};
+pub use sync::{SyncFifo, SyncLifo, SyncOrdered};
+pub use wait::AsyncList;
+
+#[cfg(test)]
+pub mod deque_test;
+
+#[cfg(test)]
+pub mod format_test;
 
 #[cfg(test)]
 pub mod list_test;
 
 #[cfg(test)]
 pub mod node_test;
+
+#[cfg(test)]
+pub mod priority_test;
+
+#[cfg(all(test, feature = "serde"))]
+pub mod serde_test;
+
+#[cfg(test)]
+pub mod snapshot_test;
+
+#[cfg(test)]
+pub mod sync_test;
+
+#[cfg(test)]
+pub mod wait_test;
@@ -1,26 +1,65 @@
 use std::cell::RefCell;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::ops::Deref;
 use std::rc::Rc;
 
 #[derive(Debug, Default, PartialOrd)]
-pub enum Node<T: Clone + Copy> {
+pub enum Node<T> {
     #[default]
     Nil,
     Content {
         value: T,
-        next: RefCell<Rc<Node<T>>>,
+        next: Link<T>,
     },
 }
 
-impl<T> Node<T>
-where
-    T: Clone + Copy,
-{
+// Pointer from a node to the one after it.
+//
+// Letting the compiler drop a chain of `Rc<Node>` takes a stack frame
+// per node, so a long enough list overflows the stack when dropped.
+// `Link` unlinks the rest of the chain one node at a time instead.
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct Link<T>(RefCell<Rc<Node<T>>>);
+
+impl<T> Link<T> {
+    pub fn new(node: Rc<Node<T>>) -> Self {
+        Link(RefCell::new(node))
+    }
+}
+
+impl<T> Deref for Link<T> {
+    type Target = RefCell<Rc<Node<T>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> Drop for Link<T> {
+    fn drop(&mut self) {
+        let next = self.0.get_mut();
+        // nothing to do when the rest of the chain
+        // is empty or still used by someone else.
+        if next.is_nil() || Rc::strong_count(next) > 1 {
+            return;
+        };
+
+        // every unlinked node gets the same nil, so dropping
+        // it doesn't go any further down the chain.
+        let nil = Node::nil();
+        let mut current = std::mem::replace(next, nil.clone());
+        while let Ok(Node::Content { value: _, mut next }) = Rc::try_unwrap(current) {
+            current = std::mem::replace(next.0.get_mut(), nil.clone());
+        }
+    }
+}
+
+impl<T> Node<T> {
     pub fn new(val: T) -> Rc<Node<T>> {
         Rc::new(Node::Content {
             value: val,
-            next: RefCell::new(Node::nil()),
+            next: Link::new(Node::nil()),
         })
     }
 
@@ -29,10 +68,7 @@ where
     }
 
     pub fn is_nil(&self) -> bool {
-        match self {
-            Node::Nil => true,
-            _ => false,
-        }
+        matches!(self, Node::Nil)
     }
 
     pub fn next(&self) -> Option<&RefCell<Rc<Node<T>>>> {
@@ -40,7 +76,7 @@ where
             Node::Content { value: _, next } => {
                 let node = next.borrow();
                 match node.is_nil() {
-                    false => Some(next),
+                    false => Some(&next.0),
                     true => None,
                 }
             }
@@ -56,33 +92,70 @@ where
         };
     }
 
-    pub fn get_value(&self) -> Option<T> {
+    pub fn get_value(&self) -> Option<&T> {
         match self {
-            Node::Content { value, next: _ } => Some(*value),
+            Node::Content { value, next: _ } => Some(value),
             Node::Nil => None,
         }
     }
+
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            Node::Content { value, next: _ } => Some(value),
+            Node::Nil => None,
+        }
+    }
+}
+
+impl<T> Node<T>
+where
+    T: Clone,
+{
+    // Moves the value out of `node` when this is the last reference
+    // to it, otherwise the value is cloned.
+    pub fn take_value(node: Rc<Node<T>>) -> Option<T> {
+        match Rc::try_unwrap(node) {
+            Ok(node) => node.into_value(),
+            Err(node) => node.get_value().cloned(),
+        }
+    }
 }
 
 impl<T> PartialEq for Node<T>
 where
-    T: Clone + Copy + PartialEq,
+    T: PartialEq,
 {
     fn eq(&self, other: &Self) -> bool {
         self.get_value() == other.get_value()
     }
 }
 
+// Written out one node at a time rather than recursing into `next`,
+// long chains would overflow the stack otherwise.
 impl<T> Display for Node<T>
 where
-    T: Clone + Copy + Display,
+    T: Display,
 {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        match self {
-            Node::Nil => fmt.write_str("nil"),
-            Node::Content { value, next } => {
-                fmt.write_fmt(format_args!("[ {} -> {}]", value, next.borrow()))
-            }
+        let mut depth = 0;
+        if let Node::Content { value, next: _ } = self {
+            fmt.write_fmt(format_args!("[ {} -> ", value))?;
+            depth += 1;
+        };
+
+        let mut next = self.next().map(|n| n.borrow().clone());
+        while let Some(node) = next {
+            if let Node::Content { value, next: _ } = &*node {
+                fmt.write_fmt(format_args!("[ {} -> ", value))?;
+                depth += 1;
+            };
+            next = node.next().map(|n| n.borrow().clone());
+        }
+
+        fmt.write_str("nil")?;
+        for _ in 0..depth {
+            fmt.write_str("]")?;
         }
+        Ok(())
     }
 }
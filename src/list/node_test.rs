@@ -3,7 +3,7 @@ use super::node::Node;
 #[test]
 fn test_node_get_value() {
     let v1 = Node::new(1);
-    assert_eq!(v1.get_value().unwrap(), 1);
+    assert_eq!(v1.get_value(), Some(&1));
 
     assert!(v1.next().is_none());
 }
@@ -14,24 +14,24 @@ fn test_node_set_next() {
     let v2 = Node::new(2);
     v1.set_next(v2);
 
-    assert_eq!(v1.next().unwrap().borrow().get_value().unwrap(), 2);
+    assert_eq!(v1.next().unwrap().borrow().get_value(), Some(&2));
 }
 #[test]
 fn test_node_get_next_and_set_next() {
     let v1 = Node::new(1);
-    assert_eq!(v1.get_value().unwrap(), 1);
+    assert_eq!(v1.get_value(), Some(&1));
 
     let v2 = Node::new(2);
     v1.set_next(v2);
 
     let v2 = v1.next().unwrap().borrow();
-    assert_eq!(v2.get_value().unwrap(), 2);
+    assert_eq!(v2.get_value(), Some(&2));
 
     let v3 = Node::new(3);
     v2.set_next(v3);
 
     let v3 = v2.next().unwrap().borrow();
-    assert_eq!(v3.get_value().unwrap(), 3);
+    assert_eq!(v3.get_value(), Some(&3));
 }
 
 #[test]
@@ -48,15 +48,15 @@ fn test_node_get_next_and_set_next_with_list() {
     let v0 = Node::new(0);
     v0.set_next(v1);
 
-    assert_eq!(v0.get_value(), Some(0));
+    assert_eq!(v0.get_value(), Some(&0));
     let v1 = v0.next().unwrap().borrow();
-    assert_eq!(v1.get_value(), Some(1));
+    assert_eq!(v1.get_value(), Some(&1));
 
     let v2 = v1.next().unwrap().borrow();
-    assert_eq!(v2.get_value(), Some(2));
+    assert_eq!(v2.get_value(), Some(&2));
 
     let v3 = v2.next().unwrap().borrow();
-    assert_eq!(v3.get_value(), Some(3));
+    assert_eq!(v3.get_value(), Some(&3));
 }
 
 #[test]
@@ -91,11 +91,41 @@ fn test_node_reshuffle() {
     v5.set_next(v7);
 
     let v5 = v1.next().unwrap().borrow();
-    assert_eq!(v5.get_value(), Some(5));
+    assert_eq!(v5.get_value(), Some(&5));
 
     let v7 = v5.next().unwrap().borrow();
-    assert_eq!(v7.get_value(), Some(7));
+    assert_eq!(v7.get_value(), Some(&7));
 
     let v10 = v7.next().unwrap().borrow();
-    assert_eq!(v10.get_value(), Some(10));
+    assert_eq!(v10.get_value(), Some(&10));
+}
+
+#[test]
+fn test_node_take_value() {
+    let v1 = Node::new("one".to_string());
+    assert_eq!(Node::take_value(v1), Some("one".to_string()));
+
+    let v2 = Node::new("two".to_string());
+    let shared = v2.clone();
+    assert_eq!(Node::take_value(v2), Some("two".to_string()));
+    assert_eq!(shared.get_value(), Some(&"two".to_string()));
+
+    assert_eq!(Node::<String>::take_value(Node::nil()), None);
+}
+
+#[test]
+fn test_node_long_chain_drop_and_display() {
+    let head = Node::new(0);
+    let mut last = head.clone();
+    for i in 1..1_000_000 {
+        let node = Node::new(i % 10);
+        last.set_next(node.clone());
+        last = node;
+    }
+    drop(last);
+
+    let s = head.to_string();
+    assert!(s.starts_with("[ 0 -> [ 1 -> "));
+    assert!(s.contains("[ 8 -> [ 9 -> nil]]]"));
+    drop(head);
 }
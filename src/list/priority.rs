@@ -0,0 +1,191 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::list::{List, Methods};
+use super::Node;
+
+impl<T> List<T>
+where
+    T: Clone + PartialOrd,
+{
+    pub fn priority() -> Box<Priority<T>> {
+        Box::new(Priority {
+            heap: RefCell::new(Vec::new()),
+        })
+    }
+}
+
+// priority queue backed by a binary min-heap, `pop` always
+// returns the smallest value.
+pub struct Priority<T> {
+    heap: RefCell<Vec<T>>,
+}
+
+impl<T> Priority<T>
+where
+    T: PartialOrd,
+{
+    fn sift_up(heap: &mut [T], mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / 2;
+            if heap[idx] >= heap[parent] {
+                return;
+            };
+            heap.swap(idx, parent);
+            idx = parent;
+        }
+    }
+
+    fn remove_at(heap: &mut Vec<T>, idx: usize) -> T {
+        let val = heap.swap_remove(idx);
+        if idx < heap.len() {
+            // the value moved into `idx` may belong
+            // further up or further down.
+            Self::sift_down(heap, idx);
+            Self::sift_up(heap, idx);
+        };
+        val
+    }
+
+    // restores the heap property after arbitrary changes.
+    fn heapify(heap: &mut [T]) {
+        for idx in (0..heap.len() / 2).rev() {
+            Self::sift_down(heap, idx);
+        }
+    }
+
+    fn sift_down(heap: &mut [T], mut idx: usize) {
+        loop {
+            let (left, right) = (2 * idx + 1, 2 * idx + 2);
+            let mut smallest = idx;
+
+            if left < heap.len() && heap[left] < heap[smallest] {
+                smallest = left;
+            };
+            if right < heap.len() && heap[right] < heap[smallest] {
+                smallest = right;
+            };
+            if smallest == idx {
+                return;
+            };
+            heap.swap(idx, smallest);
+            idx = smallest;
+        }
+    }
+}
+
+impl<T> Methods<T> for Priority<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn len(&self) -> usize {
+        self.heap.borrow().len()
+    }
+
+    // the heap isn't sorted, so this builds a sorted
+    // copy of it as a `Node` chain.
+    fn head(&self) -> Rc<Node<T>> {
+        let mut values = self.heap.borrow().clone();
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut head = Node::nil();
+        for val in values {
+            let node = Node::new(val);
+            node.set_next(head);
+            head = node;
+        }
+        head
+    }
+
+    fn push(&self, val: T) {
+        let mut heap = self.heap.borrow_mut();
+        heap.push(val);
+        let last = heap.len() - 1;
+        Self::sift_up(&mut heap, last);
+    }
+
+    fn pop(&self) -> Option<T> {
+        let mut heap = self.heap.borrow_mut();
+        if heap.is_empty() {
+            return None;
+        };
+
+        let min = heap.swap_remove(0);
+        Self::sift_down(&mut heap, 0);
+        Some(min)
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.heap.borrow().first().cloned()
+    }
+
+    // the largest value is one of the leaves, which
+    // make up the second half of the heap.
+    fn peek_last(&self) -> Option<T> {
+        let heap = self.heap.borrow();
+        let mut leaves = heap[heap.len() / 2..].iter();
+        let first = leaves.next()?;
+        let max = leaves.fold(first, |max, v| if v > max { v } else { max });
+        Some(max.clone())
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.heap.borrow().iter().any(|v| v == val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        let mut heap = self.heap.borrow_mut();
+        match heap.iter().position(|v| v == val) {
+            Some(idx) => {
+                Self::remove_at(&mut heap, idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        let before = self.len();
+        self.retain(&mut |v| v != val);
+        before - self.len()
+    }
+
+    // the heap isn't sorted, so this looks at every match
+    // to find the one `pop` would get to first.
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        let mut heap = self.heap.borrow_mut();
+        let mut first: Option<usize> = None;
+        for (idx, val) in heap.iter().enumerate() {
+            if first.is_none_or(|f| val < &heap[f]) && pred(val) {
+                first = Some(idx);
+            };
+        }
+        Some(Self::remove_at(&mut heap, first?))
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        let mut heap = self.heap.borrow_mut();
+        heap.retain(|v| keep(v));
+        Self::heapify(&mut heap);
+    }
+}
+
+impl<T> FromIterator<T> for Priority<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let l = *List::priority();
+        iter.into_iter().for_each(|val| l.push(val));
+        l
+    }
+}
+
+impl<T> Extend<T> for Priority<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|val| self.push(val));
+    }
+}
@@ -0,0 +1,143 @@
+use super::list::{List, Methods};
+use super::priority::Priority;
+
+// small deterministic generator so the tests don't need `rand`.
+fn pseudo_random(n: usize) -> Vec<u32> {
+    let mut x: u32 = 42;
+    (0..n)
+        .map(|_| {
+            x = x.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            x % 1000
+        })
+        .collect()
+}
+
+#[test]
+fn test_priority_push_and_pop() {
+    let l = List::priority();
+
+    l.push(3);
+    l.push(1);
+    l.push(4);
+    l.push(2);
+
+    assert_eq!(l.len(), 4);
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.pop(), Some(2));
+    assert_eq!(l.pop(), Some(3));
+    assert_eq!(l.pop(), Some(4));
+    assert_eq!(l.pop(), None);
+    assert!(l.is_empty());
+}
+
+#[test]
+fn test_priority_pops_sorted() {
+    let l = List::priority();
+    let mut values = pseudo_random(5000);
+
+    for val in &values {
+        l.push(*val);
+    }
+    values.sort();
+
+    let mut popped = vec![];
+    while let Some(v) = l.pop() {
+        popped.push(v);
+    }
+    assert_eq!(popped, values);
+}
+
+#[test]
+fn test_priority_remove_and_contains() {
+    let l = List::priority();
+    for val in pseudo_random(200) {
+        l.push(val);
+    }
+    l.push(5000);
+
+    assert!(l.contains(&5000));
+    assert!(l.remove(&5000));
+    assert!(!l.contains(&5000));
+    assert!(!l.remove(&5000));
+
+    // removing from the middle keeps the heap valid
+    let mut expected = pseudo_random(200);
+    for val in expected.clone().iter().step_by(3) {
+        assert!(l.remove(val));
+        let idx = expected.iter().position(|v| v == val).unwrap();
+        expected.remove(idx);
+    }
+    expected.sort();
+
+    assert_eq!(l.len(), expected.len());
+    assert_eq!(l.to_vec(), expected);
+    for val in expected {
+        assert_eq!(l.pop(), Some(val));
+    }
+}
+
+#[test]
+fn test_priority_head_and_iter_are_sorted() {
+    let l = List::priority();
+    for val in [2.5, 0.5, 1.5] {
+        l.push(val);
+    }
+
+    assert_eq!(l.head().get_value(), Some(&0.5));
+    assert_eq!(l.iter().collect::<Vec<_>>(), vec![0.5, 1.5, 2.5]);
+    assert_eq!(l.len(), 3);
+}
+
+#[test]
+fn test_priority_as_dyn_methods() {
+    let lists: Vec<Box<dyn Methods<i32>>> = vec![List::ordered(), List::priority()];
+
+    for l in &lists {
+        for val in [1, 5, 2, 3] {
+            l.push(val);
+        }
+    }
+    for l in &lists {
+        assert_eq!(l.pop(), Some(1));
+        assert_eq!(l.to_vec(), vec![2, 3, 5]);
+    }
+}
+
+#[test]
+fn test_priority_from_iter_and_extend() {
+    let mut l: Priority<u32> = pseudo_random(50).into_iter().collect();
+    l.extend([2000, 1]);
+
+    let mut expected = pseudo_random(50);
+    expected.extend([2000, 1]);
+    expected.sort();
+    assert_eq!(l.to_vec(), expected);
+}
+
+#[test]
+fn test_priority_drop_million() {
+    let l = List::priority();
+    for i in (0..1_000_000).rev() {
+        l.push(i);
+    }
+    assert_eq!(l.len(), 1_000_000);
+
+    // `head` builds a chain of every value
+    let head = l.head();
+    assert_eq!(head.get_value(), Some(&0));
+    drop(head);
+    drop(l);
+}
+
+#[test]
+fn test_priority_peek_last_is_max() {
+    let l = List::priority();
+    let values = pseudo_random(200);
+    for val in values.iter() {
+        l.push(*val);
+        assert_eq!(l.peek_last(), l.to_vec().last().cloned());
+    }
+
+    assert_eq!(l.peek(), values.iter().min().cloned());
+    assert_eq!(l.peek_last(), values.iter().max().cloned());
+}
@@ -0,0 +1,142 @@
+// serde support, enabled with the `serde` feature.
+//
+// Lists and nodes are plain sequences of values, head first, so
+// they look the same as a `Vec` in any format.
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use serde::de::Error;
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::list::{Fifo, Lifo, ListIterator, Methods, Ordered};
+use super::node::{Link, Node};
+
+// some formats (eg: bincode) need the length up front.
+fn serialize_values<T, S>(
+    len: usize,
+    values: ListIterator<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Clone + Serialize,
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(len))?;
+    for val in values {
+        seq.serialize_element(&val)?;
+    }
+    seq.end()
+}
+
+impl<T> Serialize for Lifo<T>
+where
+    T: Clone + PartialEq + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_values(self.len(), self.iter(), serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Lifo<T>
+where
+    T: Clone + PartialEq + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // pushed in reverse so the first value ends up on top again.
+        let values = Vec::<T>::deserialize(deserializer)?;
+        Ok(values.into_iter().rev().collect())
+    }
+}
+
+impl<T> Serialize for Fifo<T>
+where
+    T: Clone + PartialEq + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_values(self.len(), self.iter(), serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Fifo<T>
+where
+    T: Clone + PartialEq + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        Ok(values.into_iter().collect())
+    }
+}
+
+impl<T> Serialize for Ordered<T>
+where
+    T: Clone + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_values(self.len(), self.iter(), serializer)
+    }
+}
+
+// Comparators can't be serialized, the list comes back in the natural
+// order of its values and anything out of that order is rejected
+// rather than silently re-sorted.
+impl<'de, T> Deserialize<'de> for Ordered<T>
+where
+    T: Clone + PartialOrd + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        if let Some(idx) = values
+            .windows(2)
+            .position(|w| w[0].partial_cmp(&w[1]) == Some(Ordering::Greater))
+        {
+            return Err(D::Error::custom(format!(
+                "ordered list is not sorted at position {}",
+                idx + 1
+            )));
+        };
+        Ok(values.into_iter().collect())
+    }
+}
+
+impl<T> Serialize for Node<T>
+where
+    T: Serialize,
+{
+    // a node is serialized along with every node after it.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut rest = vec![];
+        let mut next = self.next().map(|n| n.borrow().clone());
+        while let Some(node) = next {
+            next = node.next().map(|n| n.borrow().clone());
+            rest.push(node);
+        }
+
+        let values: Vec<&T> = self
+            .get_value()
+            .into_iter()
+            .chain(rest.iter().filter_map(|n| n.get_value()))
+            .collect();
+        serializer.collect_seq(values)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Node<T>
+where
+    T: Deserialize<'de>,
+{
+    // an empty sequence is `Nil`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+
+        let mut node = Node::Nil;
+        for value in values.into_iter().rev() {
+            node = Node::Content {
+                value,
+                next: Link::new(Rc::new(node)),
+            };
+        }
+        Ok(node)
+    }
+}
@@ -0,0 +1,98 @@
+use std::rc::Rc;
+
+use super::list::{Fifo, Lifo, List, Methods, Ordered};
+use super::Node;
+
+#[test]
+fn test_serde_lifo_keeps_order() {
+    let l = Lifo::from(vec![1, 2, 3]);
+
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, "[3,2,1]");
+
+    let back: Lifo<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_vec(), vec![3, 2, 1]);
+    assert_eq!(back.pop(), Some(3));
+}
+
+#[test]
+fn test_serde_fifo_keeps_order() {
+    let l = Fifo::from(vec!["a".to_string(), "b".to_string()]);
+
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, r#"["a","b"]"#);
+
+    let back: Fifo<String> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.pop(), Some("a".to_string()));
+    assert_eq!(back.pop(), Some("b".to_string()));
+}
+
+#[test]
+fn test_serde_ordered_round_trip() {
+    let l = Ordered::from(vec![3, 1, 2]);
+
+    let json = serde_json::to_string(&l).unwrap();
+    assert_eq!(json, "[1,2,3]");
+
+    let back: Ordered<i32> = serde_json::from_str(&json).unwrap();
+    back.push(0);
+    assert_eq!(back.to_vec(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_serde_ordered_rejects_unsorted() {
+    let err = serde_json::from_str::<Ordered<i32>>("[1,3,2]")
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("not sorted at position 2"));
+
+    // equal values are fine
+    let l: Ordered<i32> = serde_json::from_str("[1,1,2]").unwrap();
+    assert_eq!(l.to_vec(), vec![1, 1, 2]);
+}
+
+#[test]
+fn test_serde_bincode_round_trip() {
+    let l = Fifo::from(vec![1u64, 2, 3]);
+    let bytes = bincode::serialize(&l).unwrap();
+    let back: Fifo<u64> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back.to_vec(), vec![1, 2, 3]);
+
+    let l = Lifo::from(vec![1u64, 2, 3]);
+    let bytes = bincode::serialize(&l).unwrap();
+    let back: Lifo<u64> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back.to_vec(), vec![3, 2, 1]);
+
+    let l = *List::ordered_desc();
+    l.push(1i64);
+    l.push(2i64);
+    let bytes = bincode::serialize(&l).unwrap();
+    assert!(bincode::deserialize::<Ordered<i64>>(&bytes).is_err());
+}
+
+#[test]
+fn test_serde_node_chain() {
+    let head = Node::new(1);
+    let second = Node::new(2);
+    second.set_next(Node::new(3));
+    head.set_next(second);
+
+    let json = serde_json::to_string(&*head).unwrap();
+    assert_eq!(json, "[1,2,3]");
+
+    let back: Node<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.to_string(), head.to_string());
+
+    let bytes = bincode::serialize(&*head).unwrap();
+    let back: Node<i32> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back.to_string(), "[ 1 -> [ 2 -> [ 3 -> nil]]]");
+}
+
+#[test]
+fn test_serde_node_nil() {
+    let nil: Rc<Node<i32>> = Node::nil();
+    assert_eq!(serde_json::to_string(&*nil).unwrap(), "[]");
+
+    let back: Node<i32> = serde_json::from_str("[]").unwrap();
+    assert!(back.is_nil());
+}
@@ -0,0 +1,427 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::list::{Fifo, Lifo, List, Methods, Ordered};
+
+// Snapshot file layout, integers are little endian:
+//
+//   magic    4 bytes  "LIST"
+//   version  u8
+//   kind     u8       see `Kind`
+//   count    u64      number of values
+//   values   count x (u32 length, encoded value)
+//   checksum u32      crc32 of everything before it
+//
+// Values are written head first.
+const MAGIC: &[u8; 4] = b"LIST";
+const VERSION: u8 = 1;
+
+// Turns list values into bytes and back for snapshots.
+pub trait Codec: Sized {
+    fn encode(&self, out: &mut Vec<u8>);
+    // `None` when the bytes aren't a valid value.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! le_bytes_codec {
+    ($($t:ty),*) => {
+        $(
+            impl Codec for $t {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(bytes: &[u8]) -> Option<Self> {
+                    bytes.try_into().ok().map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+le_bytes_codec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Codec for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl Codec for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+}
+
+impl Codec for Vec<u8> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self);
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        Some(bytes.to_vec())
+    }
+}
+
+// Which list a snapshot was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Lifo,
+    Fifo,
+    Ordered,
+}
+
+impl Kind {
+    fn to_byte(self) -> u8 {
+        match self {
+            Kind::Lifo => 0,
+            Kind::Fifo => 1,
+            Kind::Ordered => 2,
+        }
+    }
+
+    fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Kind::Lifo),
+            1 => Some(Kind::Fifo),
+            2 => Some(Kind::Ordered),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(io::Error),
+    // not a snapshot file at all
+    BadMagic,
+    UnsupportedVersion(u8),
+    UnknownKind(u8),
+    // snapshot of a different list than the one being loaded
+    WrongKind { expected: Kind, found: Kind },
+    // file ends before the snapshot does
+    Truncated,
+    // bytes left over after the checksum
+    TrailingData,
+    ChecksumMismatch,
+    // value at this position couldn't be decoded
+    InvalidValue(u64),
+}
+
+impl Display for SnapshotError {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            SnapshotError::Io(e) => write!(fmt, "snapshot i/o error: {}", e),
+            SnapshotError::BadMagic => write!(fmt, "not a list snapshot"),
+            SnapshotError::UnsupportedVersion(v) => {
+                write!(fmt, "unsupported snapshot version {}", v)
+            }
+            SnapshotError::UnknownKind(k) => write!(fmt, "unknown list kind {} in snapshot", k),
+            SnapshotError::WrongKind { expected, found } => write!(
+                fmt,
+                "snapshot is of a {:?} list, expected {:?}",
+                found, expected
+            ),
+            SnapshotError::Truncated => write!(fmt, "snapshot is truncated"),
+            SnapshotError::TrailingData => write!(fmt, "unexpected data after snapshot"),
+            SnapshotError::ChecksumMismatch => write!(fmt, "snapshot checksum mismatch"),
+            SnapshotError::InvalidValue(idx) => {
+                write!(fmt, "invalid value at position {} in snapshot", idx)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<io::Error> for SnapshotError {
+    fn from(e: io::Error) -> Self {
+        SnapshotError::Io(e)
+    }
+}
+
+// crc32 (IEEE), bit by bit as snapshots and log records are small enough
+// not to need a lookup table.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn write_snapshot<T, W>(
+    mut w: W,
+    kind: Kind,
+    len: usize,
+    values: impl Iterator<Item = T>,
+) -> Result<(), SnapshotError>
+where
+    T: Codec,
+    W: Write,
+{
+    let mut buf = Vec::new();
+    buf.extend_from_slice(MAGIC);
+    buf.push(VERSION);
+    buf.push(kind.to_byte());
+    buf.extend_from_slice(&(len as u64).to_le_bytes());
+
+    let mut value = Vec::new();
+    for val in values {
+        value.clear();
+        val.encode(&mut value);
+        let vlen = u32::try_from(value.len()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "value too large for snapshot")
+        })?;
+        buf.extend_from_slice(&vlen.to_le_bytes());
+        buf.extend_from_slice(&value);
+    }
+
+    let checksum = crc32(&buf);
+    buf.extend_from_slice(&checksum.to_le_bytes());
+
+    w.write_all(&buf)?;
+    w.flush()?;
+    Ok(())
+}
+
+// Cursor over the snapshot bytes, running out of them means
+// the file was cut short.
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], SnapshotError> {
+        if self.buf.len() < n {
+            return Err(SnapshotError::Truncated);
+        };
+        let (head, rest) = self.buf.split_at(n);
+        self.buf = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, SnapshotError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, SnapshotError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+fn read_snapshot<T, R>(mut r: R) -> Result<(Kind, Vec<T>), SnapshotError>
+where
+    T: Codec,
+    R: Read,
+{
+    let mut data = Vec::new();
+    r.read_to_end(&mut data)?;
+
+    let mut reader = Reader { buf: &data };
+    let magic = reader
+        .take(MAGIC.len())
+        .map_err(|_| SnapshotError::BadMagic)?;
+    if magic != MAGIC {
+        return Err(SnapshotError::BadMagic);
+    };
+    let version = reader.u8()?;
+    if version != VERSION {
+        return Err(SnapshotError::UnsupportedVersion(version));
+    };
+    let kind = reader.u8()?;
+    let kind = Kind::from_byte(kind).ok_or(SnapshotError::UnknownKind(kind))?;
+    let count = reader.u64()?;
+
+    // every value takes at least its 4 byte length, don't trust
+    // `count` any further than that before allocating.
+    let mut encoded = Vec::with_capacity((count as usize).min(reader.buf.len() / 4));
+    for _ in 0..count {
+        let len = reader.u32()? as usize;
+        encoded.push(reader.take(len)?);
+    }
+
+    let body_len = data.len() - reader.buf.len();
+    let checksum = reader.u32()?;
+    if !reader.buf.is_empty() {
+        return Err(SnapshotError::TrailingData);
+    };
+    if checksum != crc32(&data[..body_len]) {
+        return Err(SnapshotError::ChecksumMismatch);
+    };
+
+    let values = encoded
+        .into_iter()
+        .enumerate()
+        .map(|(idx, bytes)| T::decode(bytes).ok_or(SnapshotError::InvalidValue(idx as u64)))
+        .collect::<Result<Vec<T>, _>>()?;
+    Ok((kind, values))
+}
+
+fn read_kind<T, R>(r: R, expected: Kind) -> Result<Vec<T>, SnapshotError>
+where
+    T: Codec,
+    R: Read,
+{
+    match read_snapshot(r)? {
+        (found, values) if found == expected => Ok(values),
+        (found, _) => Err(SnapshotError::WrongKind { expected, found }),
+    }
+}
+
+// The snapshot goes to a temp file next to `path` which then replaces
+// it, so a crash half way through leaves the previous one intact.
+fn save<P: AsRef<Path>>(
+    path: P,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), SnapshotError>,
+) -> Result<(), SnapshotError> {
+    let path = path.as_ref();
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    if let Err(e) = write_synced(&tmp, write).and_then(|_| rename(&tmp, path)) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    };
+
+    // the rename itself is only on disk once the directory is synced
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    };
+    Ok(())
+}
+
+fn write_synced(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> Result<(), SnapshotError>,
+) -> Result<(), SnapshotError> {
+    let mut w = BufWriter::new(File::create(path)?);
+    write(&mut w)?;
+    let file = w.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()?;
+    Ok(())
+}
+
+fn rename(from: &Path, to: &Path) -> Result<(), SnapshotError> {
+    fs::rename(from, to)?;
+    Ok(())
+}
+
+impl<T> List<T>
+where
+    T: Codec + Clone + PartialOrd + 'static,
+{
+    // reads back a snapshot of any kind of list.
+    pub fn read_from<R: Read>(r: R) -> Result<Box<dyn Methods<T>>, SnapshotError> {
+        let (kind, values) = read_snapshot(r)?;
+        Ok(match kind {
+            Kind::Lifo => Box::new(values.into_iter().rev().collect::<Lifo<T>>()),
+            Kind::Fifo => Box::new(values.into_iter().collect::<Fifo<T>>()),
+            Kind::Ordered => Box::new(values.into_iter().collect::<Ordered<T>>()),
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Box<dyn Methods<T>>, SnapshotError> {
+        Self::read_from(File::open(path)?)
+    }
+}
+
+impl<T> Lifo<T>
+where
+    T: Codec + Clone + PartialEq,
+{
+    pub fn write_to<W: Write>(&self, w: W) -> Result<(), SnapshotError> {
+        write_snapshot(w, Kind::Lifo, self.len(), self.iter())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        save(path, |w| self.write_to(w))
+    }
+
+    pub fn read_from<R: Read>(r: R) -> Result<Self, SnapshotError> {
+        // values are stored head first, pushing them in
+        // reverse puts the head back on top.
+        let values = read_kind(r, Kind::Lifo)?;
+        Ok(values.into_iter().rev().collect())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        Self::read_from(File::open(path)?)
+    }
+}
+
+impl<T> Fifo<T>
+where
+    T: Codec + Clone + PartialEq,
+{
+    pub fn write_to<W: Write>(&self, w: W) -> Result<(), SnapshotError> {
+        write_snapshot(w, Kind::Fifo, self.len(), self.iter())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        save(path, |w| self.write_to(w))
+    }
+
+    pub fn read_from<R: Read>(r: R) -> Result<Self, SnapshotError> {
+        let values = read_kind(r, Kind::Fifo)?;
+        Ok(values.into_iter().collect())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        Self::read_from(File::open(path)?)
+    }
+}
+
+impl<T> Ordered<T>
+where
+    T: Codec + Clone + PartialOrd,
+{
+    pub fn write_to<W: Write>(&self, w: W) -> Result<(), SnapshotError> {
+        write_snapshot(w, Kind::Ordered, self.len(), self.iter())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        save(path, |w| self.write_to(w))
+    }
+
+    // comparators can't be stored, the list comes back
+    // in the natural order of its values.
+    pub fn read_from<R: Read>(r: R) -> Result<Self, SnapshotError> {
+        let values = read_kind(r, Kind::Ordered)?;
+        Ok(values.into_iter().collect())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        Self::read_from(File::open(path)?)
+    }
+}
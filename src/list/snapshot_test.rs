@@ -0,0 +1,209 @@
+use super::list::{Fifo, Lifo, List, Methods, Ordered};
+use super::snapshot::{Kind, SnapshotError};
+
+fn snapshot(l: &Fifo<u32>) -> Vec<u8> {
+    let mut buf = vec![];
+    l.write_to(&mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn test_snapshot_lifo_round_trip() {
+    let l = Lifo::from(vec![1, 2, 3]);
+
+    let mut buf = vec![];
+    l.write_to(&mut buf).unwrap();
+
+    let loaded = Lifo::<i32>::read_from(buf.as_slice()).unwrap();
+    assert_eq!(loaded.to_vec(), vec![3, 2, 1]);
+    assert_eq!(loaded.pop(), Some(3));
+}
+
+#[test]
+fn test_snapshot_fifo_round_trip() {
+    let l = Fifo::from(vec!["a".to_string(), "".to_string(), "ccc".to_string()]);
+
+    let mut buf = vec![];
+    l.write_to(&mut buf).unwrap();
+
+    let loaded = Fifo::<String>::read_from(buf.as_slice()).unwrap();
+    assert_eq!(loaded.to_vec(), l.to_vec());
+    assert_eq!(loaded.pop(), Some("a".to_string()));
+}
+
+#[test]
+fn test_snapshot_ordered_round_trip() {
+    let l = Ordered::from(vec![3.5, -1.0, 2.25]);
+
+    let mut buf = vec![];
+    l.write_to(&mut buf).unwrap();
+
+    let loaded = Ordered::<f64>::read_from(buf.as_slice()).unwrap();
+    assert_eq!(loaded.to_vec(), vec![-1.0, 2.25, 3.5]);
+
+    loaded.push(0.0);
+    assert_eq!(loaded.to_vec(), vec![-1.0, 0.0, 2.25, 3.5]);
+}
+
+#[test]
+fn test_snapshot_empty_list() {
+    let l: Fifo<u32> = Fifo::from(vec![]);
+
+    let loaded = Fifo::<u32>::read_from(snapshot(&l).as_slice()).unwrap();
+    assert!(loaded.is_empty());
+}
+
+#[test]
+fn test_snapshot_load_any_kind() {
+    let mut lifo = vec![];
+    Lifo::from(vec![1u64, 2]).write_to(&mut lifo).unwrap();
+    let mut ordered = vec![];
+    Ordered::from(vec![2u64, 1]).write_to(&mut ordered).unwrap();
+
+    let l = List::<u64>::read_from(lifo.as_slice()).unwrap();
+    assert_eq!(l.to_vec(), vec![2, 1]);
+
+    let l = List::<u64>::read_from(ordered.as_slice()).unwrap();
+    assert_eq!(l.to_vec(), vec![1, 2]);
+    l.push(0);
+    assert_eq!(l.to_vec(), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_snapshot_save_and_load_file() {
+    let path = std::env::temp_dir().join(format!("list-{}.snap", uuid::Uuid::new_v4()));
+
+    let l = Fifo::from(vec![vec![1u8, 2], vec![], vec![3]]);
+    l.save(&path).unwrap();
+    let loaded = Fifo::<Vec<u8>>::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.unwrap().to_vec(), vec![vec![1, 2], vec![], vec![3]]);
+}
+
+#[test]
+fn test_snapshot_save_replaces_previous() {
+    let path = std::env::temp_dir().join(format!("list-{}.snap", uuid::Uuid::new_v4()));
+    let tmp = path.with_extension("snap.tmp");
+
+    Fifo::from(vec![1u32, 2]).save(&path).unwrap();
+    // what a save cut short by a crash leaves behind
+    std::fs::write(&tmp, b"LIST").unwrap();
+    let before = Fifo::<u32>::load(&path);
+
+    Fifo::from(vec![3u32]).save(&path).unwrap();
+    let after = Fifo::<u32>::load(&path);
+    let tmp_left = tmp.exists();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(before.unwrap().to_vec(), vec![1, 2]);
+    assert_eq!(after.unwrap().to_vec(), vec![3]);
+    assert!(!tmp_left);
+}
+
+#[test]
+fn test_snapshot_failed_save_cleans_up() {
+    // a directory can't be replaced by the snapshot
+    let path = std::env::temp_dir().join(format!("list-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(&path).unwrap();
+    std::fs::write(path.join("keep"), b"").unwrap();
+
+    let res = Fifo::from(vec![1u32]).save(&path);
+    let tmp_left = path.with_extension("tmp").exists();
+    std::fs::remove_dir_all(&path).unwrap();
+
+    assert!(matches!(res, Err(SnapshotError::Io(_))));
+    assert!(!tmp_left);
+}
+
+#[test]
+fn test_snapshot_wrong_kind() {
+    let buf = snapshot(&Fifo::from(vec![1, 2]));
+
+    match Lifo::<u32>::read_from(buf.as_slice()) {
+        Err(SnapshotError::WrongKind { expected, found }) => {
+            assert_eq!(expected, Kind::Lifo);
+            assert_eq!(found, Kind::Fifo);
+        }
+        _ => panic!("expected a wrong kind error"),
+    };
+}
+
+#[test]
+fn test_snapshot_rejects_truncated() {
+    let buf = snapshot(&Fifo::from(vec![1, 2, 3]));
+
+    // every possible cut, none of them may panic or load
+    for len in 0..buf.len() {
+        let err = Fifo::<u32>::read_from(&buf[..len]).err().unwrap();
+        match len {
+            0..=3 => assert!(matches!(err, SnapshotError::BadMagic)),
+            _ => assert!(matches!(err, SnapshotError::Truncated), "{} {}", len, err),
+        };
+    }
+}
+
+#[test]
+fn test_snapshot_rejects_corrupt() {
+    let buf = snapshot(&Fifo::from(vec![1, 2, 3]));
+
+    // flipping a bit anywhere makes the file unreadable
+    for idx in 0..buf.len() {
+        let mut corrupt = buf.clone();
+        corrupt[idx] ^= 0x10;
+        assert!(Fifo::<u32>::read_from(corrupt.as_slice()).is_err());
+    }
+
+    let mut corrupt = buf.clone();
+    corrupt[0] = b'X';
+    assert!(matches!(
+        Fifo::<u32>::read_from(corrupt.as_slice()),
+        Err(SnapshotError::BadMagic)
+    ));
+
+    let mut corrupt = buf.clone();
+    corrupt[4] = 9;
+    assert!(matches!(
+        Fifo::<u32>::read_from(corrupt.as_slice()),
+        Err(SnapshotError::UnsupportedVersion(9))
+    ));
+
+    let mut corrupt = buf.clone();
+    *corrupt.last_mut().unwrap() ^= 1;
+    assert!(matches!(
+        Fifo::<u32>::read_from(corrupt.as_slice()),
+        Err(SnapshotError::ChecksumMismatch)
+    ));
+
+    let mut trailing = buf;
+    trailing.push(0);
+    assert!(matches!(
+        Fifo::<u32>::read_from(trailing.as_slice()),
+        Err(SnapshotError::TrailingData)
+    ));
+}
+
+#[test]
+fn test_snapshot_rejects_invalid_value() {
+    let mut buf = vec![];
+    Fifo::from(vec![vec![0xffu8], vec![b'a']])
+        .write_to(&mut buf)
+        .unwrap();
+
+    // valid file, but the first value isn't utf-8
+    match Fifo::<String>::read_from(buf.as_slice()) {
+        Err(SnapshotError::InvalidValue(0)) => {}
+        _ => panic!("expected an invalid value error"),
+    };
+}
+
+#[test]
+fn test_snapshot_huge_count_does_not_allocate() {
+    let mut buf = snapshot(&Fifo::from(vec![]));
+    buf[6..14].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    assert!(matches!(
+        Fifo::<u32>::read_from(buf.as_slice()),
+        Err(SnapshotError::Truncated)
+    ));
+}
@@ -0,0 +1,287 @@
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use super::list::{List, Methods};
+use super::Node;
+
+// Thread safe counterpart of `List`.
+//
+// `Node` chains are `Rc` based and can't cross threads, so the
+// values are kept in a `VecDeque` behind a mutex instead. The front
+// of the deque is the head of the list.
+pub struct SyncList<T> {
+    items: Mutex<VecDeque<T>>,
+}
+
+impl<T> Default for SyncList<T> {
+    fn default() -> Self {
+        SyncList {
+            items: Mutex::new(VecDeque::new()),
+        }
+    }
+}
+
+impl<T> SyncList<T>
+where
+    T: Clone + PartialEq,
+{
+    // a panic while holding the lock can't leave the deque half
+    // updated, so a poisoned lock is still safe to use.
+    fn items(&self) -> MutexGuard<'_, VecDeque<T>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items().is_empty()
+    }
+
+    // builds a detached copy of the list as a `Node` chain.
+    pub fn head(&self) -> Rc<Node<T>> {
+        let mut head = Node::nil();
+        for val in self.items().iter().rev() {
+            let node = Node::new(val.clone());
+            node.set_next(head);
+            head = node;
+        }
+        head
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        self.items().pop_front()
+    }
+
+    pub fn peek(&self) -> Option<T> {
+        self.items().front().cloned()
+    }
+
+    pub fn peek_last(&self) -> Option<T> {
+        self.items().back().cloned()
+    }
+
+    pub fn contains(&self, val: &T) -> bool {
+        self.items().contains(val)
+    }
+
+    pub fn remove(&self, val: &T) -> bool {
+        let mut items = self.items();
+        match items.iter().position(|v| v == val) {
+            Some(idx) => items.remove(idx).is_some(),
+            None => false,
+        }
+    }
+
+    pub fn remove_all(&self, val: &T) -> usize {
+        let mut items = self.items();
+        let before = items.len();
+        items.retain(|v| v != val);
+        before - items.len()
+    }
+
+    pub fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        let mut items = self.items();
+        let idx = items.iter().position(pred)?;
+        items.remove(idx)
+    }
+
+    pub fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.items().retain(keep)
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + PartialEq,
+{
+    pub fn sync_lifo() -> Box<SyncLifo<T>> {
+        Box::new(SyncLifo {
+            l: SyncList::default(),
+        })
+    }
+
+    pub fn sync_fifo() -> Box<SyncFifo<T>> {
+        Box::new(SyncFifo {
+            l: SyncList::default(),
+        })
+    }
+}
+
+impl<T> List<T>
+where
+    T: Clone + PartialOrd,
+{
+    pub fn sync_ordered() -> Box<SyncOrdered<T>> {
+        Box::new(SyncOrdered {
+            l: SyncList::default(),
+        })
+    }
+}
+
+// last in - first out list which is `Send + Sync`
+pub struct SyncLifo<T> {
+    l: SyncList<T>,
+}
+
+impl<T> Methods<T> for SyncLifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.l.len()
+    }
+
+    fn head(&self) -> Rc<Node<T>> {
+        self.l.head()
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.l.pop()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.l.contains(val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.l.remove(val)
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        self.l.remove_all(val)
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn push(&self, val: T) {
+        self.l.items().push_front(val);
+    }
+}
+
+// first in - first out list which is `Send + Sync`
+pub struct SyncFifo<T> {
+    l: SyncList<T>,
+}
+
+impl<T> Methods<T> for SyncFifo<T>
+where
+    T: Clone + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.l.len()
+    }
+
+    fn head(&self) -> Rc<Node<T>> {
+        self.l.head()
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.l.pop()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.l.contains(val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.l.remove(val)
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        self.l.remove_all(val)
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn push(&self, val: T) {
+        self.l.items().push_back(val);
+    }
+}
+
+// ordered list which is `Send + Sync`
+pub struct SyncOrdered<T> {
+    l: SyncList<T>,
+}
+
+impl<T> Methods<T> for SyncOrdered<T>
+where
+    T: Clone + PartialOrd,
+{
+    fn len(&self) -> usize {
+        self.l.len()
+    }
+
+    fn head(&self) -> Rc<Node<T>> {
+        self.l.head()
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.l.pop()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.l.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.l.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.l.contains(val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.l.remove(val)
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        self.l.remove_all(val)
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.l.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.l.retain(keep)
+    }
+
+    fn push(&self, val: T) {
+        // same as `Ordered`, new values go in front of equal ones.
+        let mut items = self.l.items();
+        let idx = items.partition_point(|v| *v < val);
+        items.insert(idx, val);
+    }
+}
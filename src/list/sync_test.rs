@@ -0,0 +1,193 @@
+use std::sync::Arc;
+use std::thread;
+
+use super::list::{List, Methods};
+
+const THREADS: u8 = 8;
+const PER_THREAD: u8 = 25;
+
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn test_sync_lists_are_send_and_sync() {
+    assert_send_sync(&List::<u8>::sync_lifo());
+    assert_send_sync(&List::<u8>::sync_fifo());
+    assert_send_sync(&List::<u8>::sync_ordered());
+}
+
+#[test]
+fn test_sync_lifo_push_and_pop() {
+    let l = List::sync_lifo();
+
+    l.push(1);
+    l.push(3);
+
+    assert_eq!(l.pop(), Some(3));
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.pop(), None);
+}
+
+#[test]
+fn test_sync_fifo_push_remove_and_contains() {
+    let l = List::sync_fifo();
+
+    l.push(1);
+    l.push(3);
+    l.push(2);
+
+    assert!(l.contains(&3));
+    assert!(l.remove(&3));
+    assert!(!l.contains(&3));
+    assert!(!l.remove(&3));
+
+    assert_eq!(l.len(), 2);
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.pop(), Some(2));
+    assert_eq!(l.pop(), None);
+}
+
+#[test]
+fn test_sync_ordered_push_and_pop() {
+    let l = List::sync_ordered();
+
+    l.push(3);
+    l.push(1);
+    l.push(4);
+    l.push(2);
+
+    assert_eq!(l.pop(), Some(1));
+    assert_eq!(l.pop(), Some(2));
+    assert_eq!(l.pop(), Some(3));
+    assert_eq!(l.pop(), Some(4));
+    assert_eq!(l.pop(), None);
+}
+
+#[test]
+fn test_sync_head_is_a_snapshot() {
+    let l = List::sync_fifo();
+
+    l.push(1);
+    l.push(2);
+
+    let head = l.head();
+    l.pop();
+
+    assert_eq!(head.get_value(), Some(&1));
+    assert_eq!(head.next().unwrap().borrow().get_value(), Some(&2));
+    assert_eq!(l.head().get_value(), Some(&2));
+}
+
+// pushes `PER_THREAD` values from each of `THREADS` threads while
+// other threads pop, then checks nothing got lost or duplicated.
+fn hammer(l: Arc<Box<dyn Methods<u8> + Send + Sync>>) -> Vec<u8> {
+    let mut popped = thread::scope(|s| {
+        for t in 0..THREADS {
+            let l = l.clone();
+            s.spawn(move || {
+                for i in 0..PER_THREAD {
+                    l.push(t * PER_THREAD + i);
+                }
+            });
+        }
+
+        let poppers: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let l = l.clone();
+                s.spawn(move || {
+                    let mut got = vec![];
+                    for _ in 0..PER_THREAD {
+                        if let Some(v) = l.pop() {
+                            got.push(v);
+                        };
+                    }
+                    got
+                })
+            })
+            .collect();
+
+        poppers
+            .into_iter()
+            .flat_map(|p| p.join().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    while let Some(v) = l.pop() {
+        popped.push(v);
+    }
+    popped.sort();
+    popped
+}
+
+#[test]
+fn test_sync_lists_from_many_threads() {
+    let expected: Vec<u8> = (0..THREADS * PER_THREAD).collect();
+
+    let lists: Vec<Box<dyn Methods<u8> + Send + Sync>> =
+        vec![List::sync_lifo(), List::sync_fifo(), List::sync_ordered()];
+
+    for l in lists {
+        let l = Arc::new(l);
+        assert_eq!(hammer(l.clone()), expected);
+        assert_eq!(l.len(), 0);
+    }
+}
+
+#[test]
+fn test_sync_fifo_keeps_per_thread_order() {
+    let l = Arc::new(List::sync_fifo());
+
+    thread::scope(|s| {
+        for t in 0..THREADS {
+            let l = l.clone();
+            s.spawn(move || {
+                for i in 0..PER_THREAD {
+                    l.push((t, i));
+                }
+            });
+        }
+    });
+
+    let mut last = [None; THREADS as usize];
+    while let Some((t, i)) = l.pop() {
+        let prev = last[t as usize].replace(i);
+        assert!(prev.is_none_or(|p| p < i));
+    }
+    assert!(last.iter().all(|i| *i == Some(PER_THREAD - 1)));
+}
+
+#[test]
+fn test_sync_ordered_from_many_threads_stays_sorted() {
+    let l = Arc::new(List::sync_ordered());
+
+    thread::scope(|s| {
+        for t in 0..THREADS {
+            let l = l.clone();
+            s.spawn(move || {
+                for i in (0..PER_THREAD).rev() {
+                    l.push(i * THREADS + t);
+                }
+            });
+        }
+    });
+
+    let mut prev = None;
+    while let Some(v) = l.pop() {
+        assert!(prev < Some(v));
+        prev = Some(v);
+    }
+}
+
+#[test]
+fn test_sync_drop_million() {
+    let lists: Vec<Box<dyn Methods<usize>>> =
+        vec![List::sync_lifo(), List::sync_fifo(), List::sync_ordered()];
+
+    for l in lists {
+        for i in 0..1_000_000 {
+            l.push(i);
+        }
+        assert_eq!(l.len(), 1_000_000);
+        drop(l.head());
+        drop(l);
+    }
+}
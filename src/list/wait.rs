@@ -0,0 +1,126 @@
+use std::rc::Rc;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use super::list::{Full, List, Methods};
+use super::Node;
+
+impl<T> List<T>
+where
+    T: Clone,
+{
+    // wraps a thread safe list so consumers can wait for values.
+    pub fn waiting(list: Box<dyn Methods<T> + Send + Sync>) -> AsyncList<T> {
+        AsyncList {
+            list,
+            notify: Notify::new(),
+        }
+    }
+}
+
+// List whose consumers can wait for the next value instead of
+// polling `pop`. Every push wakes up one waiting consumer.
+pub struct AsyncList<T> {
+    list: Box<dyn Methods<T> + Send + Sync>,
+    notify: Notify,
+}
+
+impl<T> AsyncList<T>
+where
+    T: Clone,
+{
+    // waits until there is a value to pop.
+    pub async fn pop_wait(&self) -> T {
+        self.pop_wait_by(|| self.list.pop()).await
+    }
+
+    // like `pop_wait` but values are taken by `take`, which is tried
+    // again after every push for as long as it returns `None`.
+    pub async fn pop_wait_by<R, F>(&self, mut take: F) -> R
+    where
+        F: FnMut() -> Option<R>,
+    {
+        loop {
+            // register interest before looking at the list so a push
+            // landing in between isn't missed.
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if let Some(val) = take() {
+                return val;
+            };
+            notified.await;
+        }
+    }
+
+    // like `pop_wait` but gives up after `timeout`.
+    pub async fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        tokio::time::timeout(timeout, self.pop_wait()).await.ok()
+    }
+}
+
+impl<T> Methods<T> for AsyncList<T>
+where
+    T: Clone,
+{
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn head(&self) -> Rc<Node<T>> {
+        self.list.head()
+    }
+
+    fn push(&self, val: T) {
+        self.list.push(val);
+        self.notify.notify_one();
+    }
+
+    fn try_push(&self, val: T) -> Result<(), Full<T>> {
+        self.list.try_push(val)?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    fn capacity(&self) -> Option<usize> {
+        self.list.capacity()
+    }
+
+    fn pop(&self) -> Option<T> {
+        self.list.pop()
+    }
+
+    fn peek(&self) -> Option<T> {
+        self.list.peek()
+    }
+
+    fn peek_last(&self) -> Option<T> {
+        self.list.peek_last()
+    }
+
+    fn contains(&self, val: &T) -> bool {
+        self.list.contains(val)
+    }
+
+    fn remove(&self, val: &T) -> bool {
+        self.list.remove(val)
+    }
+
+    fn remove_all(&self, val: &T) -> usize {
+        self.list.remove_all(val)
+    }
+
+    fn remove_first_where(&self, pred: &mut dyn FnMut(&T) -> bool) -> Option<T> {
+        self.list.remove_first_where(pred)
+    }
+
+    fn retain(&self, keep: &mut dyn FnMut(&T) -> bool) {
+        self.list.retain(keep)
+    }
+
+    fn impl_name(&self) -> &'static str {
+        self.list.impl_name()
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::list::{List, Methods};
+
+#[tokio::test]
+async fn test_pop_wait_returns_queued_value() {
+    let l = List::waiting(List::sync_fifo());
+
+    l.push(1);
+    l.push(2);
+
+    assert_eq!(l.pop_wait().await, 1);
+    assert_eq!(l.pop_wait().await, 2);
+    assert!(l.is_empty());
+}
+
+#[tokio::test]
+async fn test_pop_timeout() {
+    let l = List::<u8>::waiting(List::sync_fifo());
+
+    assert_eq!(l.pop_timeout(Duration::from_millis(20)).await, None);
+
+    l.push(7);
+    assert_eq!(l.pop_timeout(Duration::from_millis(20)).await, Some(7));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_pop_wait_wakes_on_push() {
+    let l = Arc::new(List::waiting(List::sync_fifo()));
+
+    let waiter = {
+        let l = l.clone();
+        tokio::spawn(async move { l.pop_wait().await })
+    };
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiter.is_finished());
+
+    l.push("hello".to_string());
+    let got = tokio::time::timeout(Duration::from_secs(5), waiter)
+        .await
+        .expect("waiter was not woken up")
+        .unwrap();
+    assert_eq!(got, "hello");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_many_waiters_get_each_value_once() {
+    const WAITERS: usize = 8;
+    const VALUES: usize = 400;
+
+    let l = Arc::new(List::waiting(List::sync_fifo()));
+
+    let waiters: Vec<_> = (0..WAITERS)
+        .map(|_| {
+            let l = l.clone();
+            tokio::spawn(async move {
+                let mut got = vec![];
+                while let Some(val) = l.pop_timeout(Duration::from_millis(500)).await {
+                    got.push(val);
+                }
+                got
+            })
+        })
+        .collect();
+
+    for val in 0..VALUES {
+        l.push(val);
+        if val % 50 == 0 {
+            tokio::task::yield_now().await;
+        };
+    }
+
+    let mut all = vec![];
+    for w in waiters {
+        all.extend(w.await.unwrap());
+    }
+    all.sort();
+
+    assert_eq!(all, (0..VALUES).collect::<Vec<_>>());
+    assert!(l.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_waiter_after_timed_out_waiter() {
+    let l = Arc::new(List::waiting(List::sync_fifo()));
+
+    // this waiter gives up straight away
+    assert_eq!(l.pop_timeout(Duration::from_millis(1)).await, None);
+
+    let waiter = {
+        let l = l.clone();
+        tokio::spawn(async move { l.pop_wait().await })
+    };
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    l.push(1);
+    let got = tokio::time::timeout(Duration::from_secs(5), waiter)
+        .await
+        .expect("waiter was not woken up")
+        .unwrap();
+    assert_eq!(got, 1);
+}